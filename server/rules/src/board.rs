@@ -0,0 +1,753 @@
+use std::fmt;
+
+/// Identifies the team a piece belongs to, chosen by the caller
+pub type TeamId = u32;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MoveError {
+    ColumnOutOfBounds,
+    ColumnFull,
+    ColumnEmpty,
+    /// the bottom piece of the column belongs to another team
+    NotOwnPiece,
+}
+
+impl fmt::Display for MoveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MoveError::ColumnOutOfBounds => write!(f, "Column index out of bounds"),
+            MoveError::ColumnFull => write!(f, "Cannot drop piece in a full column"),
+            MoveError::ColumnEmpty => write!(f, "Cannot pop a piece from an empty column"),
+            MoveError::NotOwnPiece => write!(f, "Cannot pop a piece of another team"),
+        }
+    }
+}
+
+impl std::error::Error for MoveError {}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Outcome {
+    /// cells (row, col) of the winning line
+    Win(Vec<(usize, usize)>),
+    /// the board is full and nobody has won
+    Draw,
+}
+
+/// Result of a piece landing on the board
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Played {
+    /// row the piece landed on, 0 being the top of the board
+    pub row: usize,
+    /// `None` if the game goes on
+    pub outcome: Option<Outcome>,
+}
+
+/// A line completed by popping a piece out, it can belong to another team than the one popping
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PopWin {
+    pub team: TeamId,
+    /// cells (row, col) of the winning line
+    pub line: Vec<(usize, usize)>,
+}
+
+/// The board could not be rebuilt from its parts
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InvalidBoard;
+
+impl fmt::Display for InvalidBoard {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Invalid board")
+    }
+}
+
+impl std::error::Error for InvalidBoard {}
+
+/// Most pieces a column can hold, one bit of a word each
+pub const MAX_ROWS: usize = u64::BITS as usize;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct TeamMask {
+    team: TeamId,
+    /// one word per column, bit `i` is set when the `i`-th piece from the bottom is the team's
+    columns: Vec<u64>,
+}
+
+/// A Connect Four board stored as one bitmask per team plus the height of each column. Rows are
+/// counted from the top, so pieces land on the highest free row index of a column.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Board {
+    rows: usize,
+    cols: usize,
+    streak: usize,
+    /// sorted by team, only teams that have pieces on the board
+    masks: Vec<TeamMask>,
+    /// number of pieces in each column
+    heights: Vec<u8>,
+}
+
+impl Board {
+    /// # Panics
+    ///
+    /// If any of `rows`, `cols` or `streak` is 0, or if `rows` is greater than [`MAX_ROWS`].
+    pub fn new(rows: usize, cols: usize, streak: usize) -> Self {
+        assert!(rows > 0 && cols > 0, "the board must not be empty");
+        assert!(rows <= MAX_ROWS, "a column must fit in a word");
+        assert!(streak > 0, "the streak must not be empty");
+        Self {
+            rows,
+            cols,
+            streak,
+            masks: vec![],
+            heights: vec![0; cols],
+        }
+    }
+
+    /// Builds a board by dropping pieces in order
+    pub fn from_moves(
+        rows: usize,
+        cols: usize,
+        streak: usize,
+        moves: impl IntoIterator<Item = (usize, TeamId)>,
+    ) -> Result<Self, MoveError> {
+        let mut board = Self::new(rows, cols, streak);
+        for (col, team) in moves {
+            board.drop_piece(col, team)?;
+        }
+        Ok(board)
+    }
+
+    /// Rebuilds a board from what [`Board::masks`] and [`Board::heights`] returned, checking that
+    /// the pieces of the teams fill exactly the columns up to their heights
+    pub fn from_parts(
+        rows: usize,
+        cols: usize,
+        streak: usize,
+        masks: impl IntoIterator<Item = (TeamId, Vec<u64>)>,
+        heights: Vec<u8>,
+    ) -> Result<Self, InvalidBoard> {
+        let mut board = Self::new(rows, cols, streak);
+        if heights.len() != cols || heights.iter().any(|&height| height as usize > rows) {
+            return Err(InvalidBoard);
+        }
+
+        let mut filled = vec![0u64; cols];
+        for (team, columns) in masks {
+            if columns.len() != cols {
+                return Err(InvalidBoard);
+            }
+            for (col, &word) in columns.iter().enumerate() {
+                if filled[col] & word != 0 {
+                    // two teams on the same cell
+                    return Err(InvalidBoard);
+                }
+                filled[col] |= word;
+            }
+            if columns.iter().any(|&word| word != 0) {
+                board.masks.push(TeamMask { team, columns });
+            }
+        }
+        if filled
+            .iter()
+            .zip(&heights)
+            .any(|(&word, &height)| word != column_mask(height as usize))
+        {
+            return Err(InvalidBoard);
+        }
+
+        board.masks.sort_by_key(|mask| mask.team);
+        if board.masks.windows(2).any(|w| w[0].team == w[1].team) {
+            return Err(InvalidBoard);
+        }
+        board.heights = heights;
+        Ok(board)
+    }
+
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    pub fn streak(&self) -> usize {
+        self.streak
+    }
+
+    /// Pieces of each team that has some on the board, sorted by team: one word per column where
+    /// bit `i` is set when the `i`-th piece from the bottom of the column is the team's
+    pub fn masks(&self) -> impl Iterator<Item = (TeamId, &[u64])> {
+        self.masks
+            .iter()
+            .map(|mask| (mask.team, mask.columns.as_slice()))
+    }
+
+    /// Number of pieces in each column
+    pub fn heights(&self) -> &[u8] {
+        &self.heights
+    }
+
+    /// Bit of `row` in the word of a column
+    fn bit(&self, row: usize) -> u64 {
+        1 << (self.rows - 1 - row)
+    }
+
+    /// Team of the piece at (`row`, `col`), `None` if the cell is empty or out of the board
+    pub fn get(&self, row: usize, col: usize) -> Option<TeamId> {
+        if row >= self.rows || col >= self.cols {
+            return None;
+        }
+        let bit = self.bit(row);
+        self.masks
+            .iter()
+            .find(|mask| mask.columns[col] & bit != 0)
+            .map(|mask| mask.team)
+    }
+
+    /// Number of pieces in the column
+    pub fn height(&self, col: usize) -> usize {
+        self.heights[col] as usize
+    }
+
+    pub fn is_full(&self) -> bool {
+        self.heights
+            .iter()
+            .all(|&height| height as usize == self.rows)
+    }
+
+    /// Columns that are not full
+    pub fn legal_moves(&self) -> impl Iterator<Item = usize> + '_ {
+        (0..self.cols).filter(|&col| self.height(col) < self.rows)
+    }
+
+    /// Index of the mask of the team, adding an empty one if the team has no pieces yet
+    fn mask_index(&mut self, team: TeamId) -> usize {
+        match self.masks.binary_search_by_key(&team, |mask| mask.team) {
+            Ok(index) => index,
+            Err(index) => {
+                self.masks.insert(
+                    index,
+                    TeamMask {
+                        team,
+                        columns: vec![0; self.cols],
+                    },
+                );
+                index
+            }
+        }
+    }
+
+    /// Drops a piece in the column without checking whether it ends the game, returns the row the
+    /// piece landed on
+    pub fn drop_piece(&mut self, col: usize, team: TeamId) -> Result<usize, MoveError> {
+        if col >= self.cols {
+            return Err(MoveError::ColumnOutOfBounds);
+        }
+        if self.height(col) == self.rows {
+            return Err(MoveError::ColumnFull);
+        }
+
+        let index = self.mask_index(team);
+        self.masks[index].columns[col] |= 1 << self.heights[col];
+        self.heights[col] += 1;
+        Ok(self.rows - self.height(col))
+    }
+
+    /// Drops a piece in the column and tells whether it has ended the game
+    pub fn play(&mut self, col: usize, team: TeamId) -> Result<Played, MoveError> {
+        let row = self.drop_piece(col, team)?;
+        let outcome = if let Some(line) = self.winning_line(row, col) {
+            Some(Outcome::Win(line))
+        } else if self.is_full() {
+            Some(Outcome::Draw)
+        } else {
+            None
+        };
+        Ok(Played { row, outcome })
+    }
+
+    /// Removes the topmost piece of the column and returns its team
+    pub fn undo(&mut self, col: usize) -> Option<TeamId> {
+        if col >= self.cols || self.heights[col] == 0 {
+            return None;
+        }
+
+        self.heights[col] -= 1;
+        let bit = 1 << self.heights[col];
+        let index = self
+            .masks
+            .iter()
+            .position(|mask| mask.columns[col] & bit != 0)?;
+        let mask = &mut self.masks[index];
+        mask.columns[col] &= !bit;
+        let team = mask.team;
+        if mask.columns.iter().all(|&word| word == 0) {
+            self.masks.remove(index);
+        }
+        Some(team)
+    }
+
+    /// The bottom piece of the column is the team's
+    pub fn can_pop(&self, col: usize, team: TeamId) -> bool {
+        col < self.cols
+            && self
+                .masks
+                .iter()
+                .any(|mask| mask.team == team && mask.columns[col] & 1 != 0)
+    }
+
+    /// Columns whose bottom piece is the team's
+    pub fn pop_moves(&self, team: TeamId) -> impl Iterator<Item = usize> + '_ {
+        (0..self.cols).filter(move |&col| self.can_pop(col, team))
+    }
+
+    /// Removes the bottom piece of the column, which must be the team's, and shifts the rest of the
+    /// column down without checking whether it ends the game
+    pub fn pop_piece(&mut self, col: usize, team: TeamId) -> Result<(), MoveError> {
+        if col >= self.cols {
+            return Err(MoveError::ColumnOutOfBounds);
+        }
+        if self.heights[col] == 0 {
+            return Err(MoveError::ColumnEmpty);
+        }
+        if !self.can_pop(col, team) {
+            return Err(MoveError::NotOwnPiece);
+        }
+
+        for mask in &mut self.masks {
+            mask.columns[col] >>= 1;
+        }
+        self.masks
+            .retain(|mask| mask.columns.iter().any(|&word| word != 0));
+        self.heights[col] -= 1;
+        Ok(())
+    }
+
+    /// Pops a piece out and tells whether it has completed a line. Every piece of the column has
+    /// moved, so lines of any team can appear at once: the team popping wins if it has one,
+    /// otherwise the win goes to the first other team that has one.
+    pub fn pop(&mut self, col: usize, team: TeamId) -> Result<Option<PopWin>, MoveError> {
+        self.pop_piece(col, team)?;
+
+        let wins = (self.rows - self.height(col)..self.rows).filter_map(|row| {
+            let line = self.winning_line(row, col)?;
+            Some(PopWin {
+                team: self.get(row, col)?,
+                line,
+            })
+        });
+        let mut first_win = None;
+        for win in wins {
+            if win.team == team {
+                return Ok(Some(win));
+            }
+            first_win.get_or_insert(win);
+        }
+        Ok(first_win)
+    }
+
+    /// Puts back a piece of the team at the bottom of the column, undoing [`Board::pop_piece`]
+    pub fn unpop(&mut self, col: usize, team: TeamId) -> Result<(), MoveError> {
+        if col >= self.cols {
+            return Err(MoveError::ColumnOutOfBounds);
+        }
+        if self.height(col) == self.rows {
+            return Err(MoveError::ColumnFull);
+        }
+
+        for mask in &mut self.masks {
+            mask.columns[col] <<= 1;
+        }
+        let index = self.mask_index(team);
+        self.masks[index].columns[col] |= 1;
+        self.heights[col] += 1;
+        Ok(())
+    }
+
+    /// Hash of the pieces on the board that stays the same across builds, to detect repeated
+    /// positions
+    pub fn position_hash(&self) -> u64 {
+        const FNV_OFFSET: u64 = 0xcbf2_9ce4_8422_2325;
+        const FNV_PRIME: u64 = 0x0100_0000_01b3;
+
+        let words = self
+            .masks
+            .iter()
+            .flat_map(|mask| std::iter::once(mask.team as u64).chain(mask.columns.iter().copied()));
+        let mut hash = FNV_OFFSET;
+        for word in words {
+            for byte in word.to_le_bytes() {
+                hash ^= byte as u64;
+                hash = hash.wrapping_mul(FNV_PRIME);
+            }
+        }
+        hash
+    }
+
+    /// Checks the lines passing through the piece at (`row`, `col`) and returns the cells of a
+    /// winning line. Only the lines through the latest move need to be checked after each move.
+    /// When the line is longer than the streak, the part of it nearest the top of the board is
+    /// returned.
+    pub fn winning_line(&self, row: usize, col: usize) -> Option<Vec<(usize, usize)>> {
+        let team = self.get(row, col)?;
+        let belongs =
+            |r: isize, c: isize| r >= 0 && c >= 0 && self.get(r as usize, c as usize) == Some(team);
+
+        // horizontal, vertical, diagonal (top-left to bottom-right), diagonal (bottom-left to top-right)
+        for (d_row, d_col) in [(0, 1), (1, 0), (1, 1), (-1, 1)] {
+            // walk back to the first cell of the line, then collect the line in order
+            let (mut r, mut c) = (row as isize, col as isize);
+            while belongs(r - d_row, c - d_col) {
+                r -= d_row;
+                c -= d_col;
+            }
+            let mut line = vec![];
+            while belongs(r, c) {
+                line.push((r as usize, c as usize));
+                r += d_row;
+                c += d_col;
+            }
+
+            if line.len() >= self.streak {
+                if d_row < 0 {
+                    line.drain(..line.len() - self.streak);
+                } else {
+                    line.truncate(self.streak);
+                }
+                return Some(line);
+            }
+        }
+
+        None
+    }
+}
+
+/// Word of a column holding `height` pieces
+fn column_mask(height: usize) -> u64 {
+    if height == MAX_ROWS {
+        u64::MAX
+    } else {
+        (1 << height) - 1
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const A: TeamId = 1;
+    const B: TeamId = 2;
+
+    fn classic() -> Board {
+        Board::new(6, 7, 4)
+    }
+
+    #[test]
+    fn pieces_stack_from_the_bottom() {
+        let mut board = classic();
+        assert_eq!(board.drop_piece(3, A), Ok(5));
+        assert_eq!(board.drop_piece(3, B), Ok(4));
+        assert_eq!(board.get(5, 3), Some(A));
+        assert_eq!(board.get(4, 3), Some(B));
+        assert_eq!(board.get(3, 3), None);
+        assert_eq!(board.height(3), 2);
+    }
+
+    #[test]
+    fn rejects_out_of_bounds_column() {
+        let mut board = classic();
+        assert_eq!(board.drop_piece(7, A), Err(MoveError::ColumnOutOfBounds));
+        assert_eq!(board.play(100, A), Err(MoveError::ColumnOutOfBounds));
+    }
+
+    #[test]
+    fn rejects_full_column() {
+        let mut board = classic();
+        for i in 0..6 {
+            board.drop_piece(0, if i % 2 == 0 { A } else { B }).unwrap();
+        }
+        assert_eq!(board.drop_piece(0, A), Err(MoveError::ColumnFull));
+        assert!(!board.legal_moves().any(|col| col == 0));
+        assert_eq!(board.legal_moves().count(), 6);
+    }
+
+    #[test]
+    fn horizontal_win() {
+        let board = Board::from_moves(6, 7, 4, [(0, A), (1, A), (2, A), (3, A)]).unwrap();
+        assert_eq!(
+            board.winning_line(5, 3),
+            Some(vec![(5, 0), (5, 1), (5, 2), (5, 3)])
+        );
+    }
+
+    #[test]
+    fn vertical_win() {
+        let mut board = Board::from_moves(6, 7, 4, [(6, A), (6, A), (6, A)]).unwrap();
+        let played = board.play(6, A).unwrap();
+        assert_eq!(played.row, 2);
+        assert_eq!(
+            played.outcome,
+            Some(Outcome::Win(vec![(2, 6), (3, 6), (4, 6), (5, 6)]))
+        );
+    }
+
+    #[test]
+    fn diagonal_win_towards_bottom_right() {
+        // A on (2,0), (3,1), (4,2), (5,3)
+        let moves = [
+            (3, A),
+            (2, B),
+            (2, A),
+            (1, B),
+            (1, B),
+            (1, A),
+            (0, B),
+            (0, B),
+            (0, B),
+            (0, A),
+        ];
+        let board = Board::from_moves(6, 7, 4, moves).unwrap();
+        assert_eq!(
+            board.winning_line(2, 0),
+            Some(vec![(2, 0), (3, 1), (4, 2), (5, 3)])
+        );
+    }
+
+    #[test]
+    fn diagonal_win_towards_top_right_at_the_border() {
+        // A on (5,3), (4,4), (3,5), (2,6) touching the right border
+        let moves = [
+            (3, A),
+            (4, B),
+            (4, A),
+            (5, B),
+            (5, B),
+            (5, A),
+            (6, B),
+            (6, B),
+            (6, B),
+            (6, A),
+        ];
+        let board = Board::from_moves(6, 7, 4, moves).unwrap();
+        assert_eq!(
+            board.winning_line(2, 6),
+            Some(vec![(5, 3), (4, 4), (3, 5), (2, 6)])
+        );
+    }
+
+    #[test]
+    fn line_longer_than_streak_returns_the_leftmost_part() {
+        let board = Board::from_moves(6, 7, 4, [(0, A), (1, A), (3, A), (4, A), (2, A)]).unwrap();
+        assert_eq!(
+            board.winning_line(5, 2),
+            Some(vec![(5, 0), (5, 1), (5, 2), (5, 3)])
+        );
+    }
+
+    #[test]
+    fn no_win_with_a_gap_or_another_team() {
+        let board = Board::from_moves(6, 7, 4, [(0, A), (1, A), (2, B), (3, A)]).unwrap();
+        assert_eq!(board.winning_line(5, 3), None);
+        assert_eq!(board.winning_line(5, 0), None);
+    }
+
+    #[test]
+    fn more_than_two_teams() {
+        const C: TeamId = 3;
+        const D: TeamId = 4;
+        let mut board = Board::new(6, 20, 4);
+        for offset in 0..3 {
+            for (i, team) in [A, B, C, D].into_iter().enumerate() {
+                let played = board.play(i * 4 + offset, team).unwrap();
+                assert_eq!(played.outcome, None);
+            }
+        }
+        assert_eq!(board.masks().count(), 4);
+        assert_eq!(board.play(16, A).unwrap().outcome, None);
+        assert_eq!(board.play(17, B).unwrap().outcome, None);
+        assert_eq!(
+            board.play(11, C).unwrap().outcome,
+            Some(Outcome::Win(vec![(5, 8), (5, 9), (5, 10), (5, 11)]))
+        );
+        assert_eq!(board.get(5, 14), Some(D));
+    }
+
+    #[test]
+    fn pop_shifts_the_column_down() {
+        let mut board = Board::from_moves(6, 7, 4, [(2, A), (2, B), (2, A)]).unwrap();
+        assert_eq!(board.pop(2, A), Ok(None));
+        assert_eq!(board.height(2), 2);
+        assert_eq!(board.get(5, 2), Some(B));
+        assert_eq!(board.get(4, 2), Some(A));
+        assert_eq!(board.get(3, 2), None);
+    }
+
+    #[test]
+    fn pop_only_own_bottom_piece() {
+        let mut board = Board::from_moves(6, 7, 4, [(2, A), (2, B)]).unwrap();
+        assert_eq!(board.pop(2, B), Err(MoveError::NotOwnPiece));
+        assert_eq!(board.pop(3, A), Err(MoveError::ColumnEmpty));
+        assert_eq!(board.pop(7, A), Err(MoveError::ColumnOutOfBounds));
+        assert_eq!(board.pop_moves(A).collect::<Vec<_>>(), vec![2]);
+        assert_eq!(board.pop_moves(B).count(), 0);
+    }
+
+    #[test]
+    fn pop_completing_a_line_of_another_team() {
+        // the piece of B above the popped one lands next to the three others of B
+        let mut board =
+            Board::from_moves(6, 7, 4, [(0, B), (1, B), (2, B), (3, A), (3, B)]).unwrap();
+        assert_eq!(
+            board.pop(3, A),
+            Ok(Some(PopWin {
+                team: B,
+                line: vec![(5, 0), (5, 1), (5, 2), (5, 3)],
+            }))
+        );
+    }
+
+    #[test]
+    fn pop_completing_lines_of_both_teams_wins_for_the_popper() {
+        let moves = [
+            (0, B),
+            (0, A),
+            (0, B),
+            (1, B),
+            (1, A),
+            (1, B),
+            (2, A),
+            (2, A),
+            (2, B),
+            (3, A),
+            (3, B),
+            (3, A),
+            (3, B),
+        ];
+        let mut board = Board::from_moves(6, 7, 4, moves).unwrap();
+        // column 3 shifts down so that A lands on the fifth row and B on the fourth one
+        assert_eq!(
+            board.pop(3, A),
+            Ok(Some(PopWin {
+                team: A,
+                line: vec![(4, 0), (4, 1), (4, 2), (4, 3)],
+            }))
+        );
+        assert!(board.winning_line(3, 3).is_some());
+    }
+
+    #[test]
+    fn unpop_restores_the_board() {
+        let before = Board::from_moves(6, 7, 4, [(2, A), (2, B), (3, A)]).unwrap();
+        let mut board = before.clone();
+        board.pop(3, A).unwrap();
+        assert_eq!(board.masks().count(), 2);
+        board.unpop(3, A).unwrap();
+        assert_eq!(board, before);
+        assert_eq!(board.position_hash(), before.position_hash());
+    }
+
+    #[test]
+    fn no_win_on_empty_cell() {
+        assert_eq!(classic().winning_line(0, 0), None);
+    }
+
+    #[test]
+    fn longer_streak() {
+        let mut board = Board::new(6, 7, 5);
+        for col in 0..4 {
+            assert_eq!(board.play(col, A).unwrap().outcome, None);
+        }
+        assert!(matches!(
+            board.play(4, A).unwrap().outcome,
+            Some(Outcome::Win(line)) if line.len() == 5
+        ));
+    }
+
+    #[test]
+    fn draw_when_last_piece_lands_without_a_win() {
+        // columns filled in pairs so that no team gets 3 in a row in any direction
+        let mut board = Board::new(4, 4, 3);
+        let pattern = [[A, A, B, B], [B, B, A, A], [A, A, B, B], [B, B, A, A]];
+        let mut last = None;
+        for teams in pattern.iter().rev() {
+            for (col, &team) in teams.iter().enumerate() {
+                last = Some(board.play(col, team).unwrap());
+            }
+        }
+        assert_eq!(last.unwrap().outcome, Some(Outcome::Draw));
+        assert!(board.is_full());
+        assert_eq!(board.legal_moves().count(), 0);
+    }
+
+    #[test]
+    fn parts_round_trip() {
+        let board = Board::from_moves(6, 7, 4, [(2, B), (2, A), (5, A), (0, B)]).unwrap();
+        let masks: Vec<(TeamId, Vec<u64>)> = board
+            .masks()
+            .map(|(team, columns)| (team, columns.to_vec()))
+            .collect();
+        assert_eq!(masks[0], (A, vec![0, 0, 0b10, 0, 0, 0b1, 0]));
+        assert_eq!(masks[1], (B, vec![0b1, 0, 0b1, 0, 0, 0, 0]));
+        assert_eq!(board.heights(), &[1, 0, 2, 0, 0, 1, 0]);
+
+        let rebuilt = Board::from_parts(6, 7, 4, masks, board.heights().to_vec()).unwrap();
+        assert_eq!(rebuilt, board);
+    }
+
+    #[test]
+    fn rejects_inconsistent_parts() {
+        // floating piece
+        assert_eq!(
+            Board::from_parts(
+                6,
+                7,
+                4,
+                [(A, vec![0b10, 0, 0, 0, 0, 0, 0])],
+                vec![1, 0, 0, 0, 0, 0, 0]
+            ),
+            Err(InvalidBoard)
+        );
+        // two teams on the same cell
+        assert_eq!(
+            Board::from_parts(
+                6,
+                7,
+                4,
+                [
+                    (A, vec![0b1, 0, 0, 0, 0, 0, 0]),
+                    (B, vec![0b1, 0, 0, 0, 0, 0, 0])
+                ],
+                vec![1, 0, 0, 0, 0, 0, 0]
+            ),
+            Err(InvalidBoard)
+        );
+        // wrong number of columns
+        assert_eq!(
+            Board::from_parts(6, 7, 4, [(A, vec![0b1])], vec![1, 0, 0, 0, 0, 0, 0]),
+            Err(InvalidBoard)
+        );
+    }
+
+    #[test]
+    fn full_height_columns() {
+        let mut board = Board::new(MAX_ROWS, 1, 4);
+        for i in 0..MAX_ROWS {
+            board.drop_piece(0, if i % 2 == 0 { A } else { B }).unwrap();
+        }
+        assert!(board.is_full());
+        let masks = board
+            .masks()
+            .map(|(team, columns)| (team, columns.to_vec()));
+        let rebuilt = Board::from_parts(MAX_ROWS, 1, 4, masks, board.heights().to_vec()).unwrap();
+        assert_eq!(rebuilt, board);
+    }
+
+    #[test]
+    fn undo_removes_the_top_piece() {
+        let mut board = Board::from_moves(6, 7, 4, [(2, A), (2, B)]).unwrap();
+        assert_eq!(board.undo(2), Some(B));
+        assert_eq!(board.undo(2), Some(A));
+        assert_eq!(board.undo(2), None);
+        assert_eq!(board, classic());
+    }
+}
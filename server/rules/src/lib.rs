@@ -0,0 +1,7 @@
+//! Rules of Connect Four on boards of any size and with any streak length, kept apart from the
+//! SpacetimeDB module so that they can be tested on their own.
+
+mod board;
+pub mod solver;
+
+pub use board::{Board, InvalidBoard, MoveError, Outcome, Played, PopWin, TeamId, MAX_ROWS};
@@ -0,0 +1,292 @@
+//! Alpha-beta search picking the best columns for a team playing against another one
+
+use crate::{Board, TeamId};
+
+const WIN_SCORE: i32 = 1_000_000;
+
+/// Columns that are the best for `me` to play against `opponent`, looking `depth` moves ahead.
+/// Empty if the board is full.
+///
+/// # Panics
+///
+/// If `depth` is 0.
+pub fn best_columns(board: &Board, me: TeamId, opponent: TeamId, depth: u32) -> Vec<usize> {
+    assert!(depth > 0, "the search must look at least one move ahead");
+
+    let mut search = Search {
+        board: board.clone(),
+        me,
+        opponent,
+    };
+
+    let mut best_score = -WIN_SCORE * 2;
+    let mut best_columns = vec![];
+    for col in search.ordered_moves() {
+        // `best_score - 1` as alpha so that columns as good as the best one get an exact score
+        let score = search.score_move(col, true, depth, best_score - 1, WIN_SCORE * 2);
+        if score > best_score {
+            best_score = score;
+            best_columns = vec![col];
+        } else if score == best_score {
+            best_columns.push(col);
+        }
+    }
+    best_columns
+}
+
+struct Search {
+    board: Board,
+    me: TeamId,
+    opponent: TeamId,
+}
+
+impl Search {
+    /// Columns that are not full, the center ones first since they tend to be the best moves
+    fn ordered_moves(&self) -> Vec<usize> {
+        let cols = self.board.cols();
+        let mut columns: Vec<usize> = self.board.legal_moves().collect();
+        columns.sort_by_key(|&col| (2 * col).abs_diff(cols - 1));
+        columns
+    }
+
+    /// Score of dropping a piece in `col` from the point of view of the team dropping it
+    fn score_move(
+        &mut self,
+        col: usize,
+        me_to_move: bool,
+        depth: u32,
+        alpha: i32,
+        beta: i32,
+    ) -> i32 {
+        let team = if me_to_move { self.me } else { self.opponent };
+        let Ok(row) = self.board.drop_piece(col, team) else {
+            return -WIN_SCORE * 2;
+        };
+
+        let score = if self.board.winning_line(row, col).is_some() {
+            // winning sooner is better than winning later
+            WIN_SCORE + depth as i32
+        } else {
+            -self.negamax(!me_to_move, depth - 1, -beta, -alpha)
+        };
+        self.board.undo(col);
+
+        score
+    }
+
+    /// Score of the position from the point of view of the team to move
+    fn negamax(&mut self, me_to_move: bool, depth: u32, mut alpha: i32, beta: i32) -> i32 {
+        let columns = self.ordered_moves();
+        if columns.is_empty() {
+            // draw
+            return 0;
+        }
+        if depth == 0 {
+            let score = self.evaluate();
+            return if me_to_move { score } else { -score };
+        }
+
+        let mut best = -WIN_SCORE * 2;
+        for col in columns {
+            let score = self.score_move(col, me_to_move, depth, alpha, beta);
+            best = best.max(score);
+            alpha = alpha.max(score);
+            if alpha >= beta {
+                break;
+            }
+        }
+        best
+    }
+
+    /// Heuristic score of the position from the point of view of `me`: every line that could
+    /// still be completed counts more the more pieces it already has
+    fn evaluate(&self) -> i32 {
+        let rows = self.board.rows() as isize;
+        let cols = self.board.cols() as isize;
+        let streak = self.board.streak() as isize;
+
+        let mut score = 0;
+        for (d_row, d_col) in [(0, 1), (1, 0), (1, 1), (-1, 1)] {
+            for row in 0..rows {
+                for col in 0..cols {
+                    let end_row = row + d_row * (streak - 1);
+                    let end_col = col + d_col * (streak - 1);
+                    if !(0..rows).contains(&end_row) || !(0..cols).contains(&end_col) {
+                        continue;
+                    }
+
+                    let mut my_count = 0;
+                    let mut opponent_count = 0;
+                    for i in 0..streak {
+                        let cell = self
+                            .board
+                            .get((row + d_row * i) as usize, (col + d_col * i) as usize);
+                        if cell == Some(self.me) {
+                            my_count += 1;
+                        } else if cell == Some(self.opponent) {
+                            opponent_count += 1;
+                        }
+                    }
+
+                    if opponent_count == 0 && my_count > 0 {
+                        score += 4i32.pow(my_count);
+                    } else if my_count == 0 && opponent_count > 0 {
+                        score -= 4i32.pow(opponent_count);
+                    }
+                }
+            }
+        }
+        score
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ME: TeamId = 1;
+    const THEM: TeamId = 2;
+
+    #[test]
+    fn takes_the_win() {
+        let board = Board::from_moves(
+            6,
+            7,
+            4,
+            [(0, ME), (0, THEM), (1, ME), (1, THEM), (2, ME), (2, THEM)],
+        )
+        .unwrap();
+        for depth in 1..=4 {
+            assert_eq!(best_columns(&board, ME, THEM, depth), vec![3]);
+        }
+    }
+
+    #[test]
+    fn blocks_the_opponent() {
+        let board = Board::from_moves(6, 7, 4, [(6, THEM), (0, ME), (6, THEM), (0, ME), (6, THEM)])
+            .unwrap();
+        for depth in 2..=4 {
+            assert_eq!(best_columns(&board, ME, THEM, depth), vec![6]);
+        }
+    }
+
+    #[test]
+    fn prefers_the_center_on_an_empty_board() {
+        let board = Board::new(6, 7, 4);
+        assert_eq!(best_columns(&board, ME, THEM, 1), vec![3]);
+        // deeper searches see the replies too, but never pick an edge
+        for depth in 2..=5 {
+            let best = best_columns(&board, ME, THEM, depth);
+            assert!(
+                !best.contains(&0) && !best.contains(&6),
+                "depth {}: {:?}",
+                depth,
+                best
+            );
+        }
+    }
+
+    /// Best columns found by a search that looks at every move, without pruning
+    fn unpruned_best_columns(board: &Board, depth: u32) -> Vec<usize> {
+        fn score_move(search: &mut Search, col: usize, me_to_move: bool, depth: u32) -> i32 {
+            let team = if me_to_move {
+                search.me
+            } else {
+                search.opponent
+            };
+            let row = search.board.drop_piece(col, team).unwrap();
+            let score = if search.board.winning_line(row, col).is_some() {
+                WIN_SCORE + depth as i32
+            } else {
+                -negamax(search, !me_to_move, depth - 1)
+            };
+            search.board.undo(col);
+            score
+        }
+
+        fn negamax(search: &mut Search, me_to_move: bool, depth: u32) -> i32 {
+            let columns = search.ordered_moves();
+            if columns.is_empty() {
+                return 0;
+            }
+            if depth == 0 {
+                let score = search.evaluate();
+                return if me_to_move { score } else { -score };
+            }
+            columns
+                .into_iter()
+                .map(|col| score_move(search, col, me_to_move, depth))
+                .max()
+                .unwrap()
+        }
+
+        let mut search = Search {
+            board: board.clone(),
+            me: ME,
+            opponent: THEM,
+        };
+        let scores: Vec<(usize, i32)> = search
+            .ordered_moves()
+            .into_iter()
+            .map(|col| (col, score_move(&mut search, col, true, depth)))
+            .collect();
+        let best = scores.iter().map(|&(_, score)| score).max();
+        scores
+            .into_iter()
+            .filter(|&(_, score)| Some(score) == best)
+            .map(|(col, _)| col)
+            .collect()
+    }
+
+    #[test]
+    fn pruning_keeps_the_best_columns() {
+        // xorshift, so that the positions are the same on every run
+        let mut seed = 0x2545_f491_4f6c_dd1du64;
+        let mut next = move || {
+            seed ^= seed << 13;
+            seed ^= seed >> 7;
+            seed ^= seed << 17;
+            seed
+        };
+
+        let mut positions = 0;
+        while positions < 50 {
+            let mut board = Board::new(6, 7, 4);
+            let mut over = false;
+            for i in 0..next() % 16 {
+                let team = if i % 2 == 0 { ME } else { THEM };
+                match board.play((next() % 7) as usize, team) {
+                    Ok(played) if played.outcome.is_some() => over = true,
+                    _ => {}
+                }
+                if over {
+                    break;
+                }
+            }
+            if over || board.is_full() {
+                continue;
+            }
+            positions += 1;
+
+            for depth in 1..=4 {
+                assert_eq!(
+                    best_columns(&board, ME, THEM, depth),
+                    unpruned_best_columns(&board, depth),
+                    "depth {} on {:?}",
+                    depth,
+                    board
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn no_columns_on_a_full_board() {
+        let moves = (0..4).flat_map(|col| {
+            (0..4).map(move |i| (col, if (col / 2 + i / 2) % 2 == 0 { ME } else { THEM }))
+        });
+        let board = Board::from_moves(4, 4, 3, moves).unwrap();
+        assert!(board.is_full());
+        assert!(best_columns(&board, ME, THEM, 2).is_empty());
+    }
+}
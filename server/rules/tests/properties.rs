@@ -0,0 +1,196 @@
+use conn4_rules::{solver, Board, MoveError, Outcome, TeamId};
+use proptest::prelude::*;
+
+const TEAMS: [TeamId; 2] = [10, 20];
+
+/// Board dimensions and a sequence of column choices, the columns are taken modulo `cols`
+fn game() -> impl Strategy<Value = (usize, usize, usize, Vec<usize>)> {
+    (4usize..=9, 4usize..=12, 3usize..=6).prop_flat_map(|(rows, cols, streak)| {
+        (
+            Just(rows),
+            Just(cols),
+            Just(streak),
+            prop::collection::vec(0..cols, 0..=rows * cols + 10),
+        )
+    })
+}
+
+/// Checks every window of the board, the way the win detection used to work
+fn full_scan_has_win(board: &Board, team: TeamId) -> bool {
+    let rows = board.rows() as isize;
+    let cols = board.cols() as isize;
+    let streak = board.streak() as isize;
+    for (d_row, d_col) in [(0, 1), (1, 0), (1, 1), (-1, 1)] {
+        for row in 0..rows {
+            for col in 0..cols {
+                let end_row = row + d_row * (streak - 1);
+                let end_col = col + d_col * (streak - 1);
+                if !(0..rows).contains(&end_row) || !(0..cols).contains(&end_col) {
+                    continue;
+                }
+                if (0..streak).all(|i| {
+                    board.get((row + d_row * i) as usize, (col + d_col * i) as usize) == Some(team)
+                }) {
+                    return true;
+                }
+            }
+        }
+    }
+    false
+}
+
+proptest! {
+    #[test]
+    fn pieces_never_float((rows, cols, streak, moves) in game()) {
+        let mut board = Board::new(rows, cols, streak);
+        let mut dropped = 0;
+        for (i, col) in moves.into_iter().enumerate() {
+            if board.drop_piece(col, TEAMS[i % 2]).is_ok() {
+                dropped += 1;
+            }
+        }
+
+        let mut counted = 0;
+        for col in 0..cols {
+            let height = board.height(col);
+            prop_assert!(height <= rows);
+            for row in 0..rows {
+                prop_assert_eq!(board.get(row, col).is_some(), row >= rows - height);
+            }
+            counted += height;
+        }
+        prop_assert_eq!(counted, dropped);
+    }
+
+    #[test]
+    fn legal_moves_are_the_columns_that_are_not_full((rows, cols, streak, moves) in game()) {
+        let mut board = Board::new(rows, cols, streak);
+        for (i, col) in moves.into_iter().enumerate() {
+            let legal: Vec<usize> = board.legal_moves().collect();
+            let result = board.drop_piece(col, TEAMS[i % 2]);
+            if legal.contains(&col) {
+                prop_assert!(result.is_ok());
+            } else {
+                prop_assert_eq!(result, Err(MoveError::ColumnFull));
+            }
+            prop_assert_eq!(board.is_full(), board.legal_moves().next().is_none());
+        }
+    }
+
+    #[test]
+    fn incremental_win_detection_matches_a_full_scan((rows, cols, streak, moves) in game()) {
+        let mut board = Board::new(rows, cols, streak);
+        for (i, col) in moves.into_iter().enumerate() {
+            let team = TEAMS[i % 2];
+            let Ok(played) = board.play(col, team) else {
+                continue;
+            };
+
+            let line = board.winning_line(played.row, col);
+            prop_assert_eq!(line.is_some(), full_scan_has_win(&board, team));
+
+            match played.outcome {
+                Some(Outcome::Win(line)) => {
+                    prop_assert_eq!(line.len(), streak);
+                    prop_assert!(line.contains(&(played.row, col)));
+                    prop_assert!(line.iter().all(|&(r, c)| board.get(r, c) == Some(team)));
+                    // the cells are consecutive along one direction
+                    let d_row = line[1].0 as isize - line[0].0 as isize;
+                    let d_col = line[1].1 as isize - line[0].1 as isize;
+                    prop_assert!(d_row.abs() <= 1 && d_col.abs() <= 1 && (d_row, d_col) != (0, 0));
+                    for pair in line.windows(2) {
+                        prop_assert_eq!(pair[1].0 as isize - pair[0].0 as isize, d_row);
+                        prop_assert_eq!(pair[1].1 as isize - pair[0].1 as isize, d_col);
+                    }
+                    break;
+                }
+                Some(Outcome::Draw) => {
+                    prop_assert!(board.is_full());
+                    prop_assert!(line.is_none());
+                    break;
+                }
+                None => prop_assert!(!board.is_full()),
+            }
+        }
+    }
+
+    #[test]
+    fn undo_restores_the_board((rows, cols, streak, moves) in game()) {
+        let mut board = Board::new(rows, cols, streak);
+        for (i, col) in moves.into_iter().enumerate() {
+            let before = board.clone();
+            if board.drop_piece(col, TEAMS[i % 2]).is_ok() {
+                let mut undone = board.clone();
+                prop_assert_eq!(undone.undo(col), Some(TEAMS[i % 2]));
+                prop_assert_eq!(undone, before);
+            }
+        }
+    }
+
+    #[test]
+    fn pops_keep_the_columns_packed_and_can_be_undone((rows, cols, streak, moves) in game()) {
+        let mut board = Board::new(rows, cols, streak);
+        for (i, col) in moves.into_iter().enumerate() {
+            let team = TEAMS[i % 2];
+            // every third move pops when it can
+            if i % 3 != 2 || !board.can_pop(col, team) {
+                let _ = board.drop_piece(col, team);
+                continue;
+            }
+
+            let before = board.clone();
+            let win = board.pop(col, team).unwrap();
+            prop_assert_eq!(board.height(col), before.height(col) - 1);
+            for row in 0..rows {
+                prop_assert_eq!(board.get(row, col).is_some(), row >= rows - board.height(col));
+            }
+            if let Some(win) = &win {
+                prop_assert!(win.line.iter().all(|&(r, c)| board.get(r, c) == Some(win.team)));
+            }
+
+            // lines that were already on the board are not the pop's doing
+            if !TEAMS.iter().any(|&team| full_scan_has_win(&before, team)) {
+                let any_win = TEAMS.iter().any(|&team| full_scan_has_win(&board, team));
+                prop_assert_eq!(win.is_some(), any_win);
+                if let Some(win) = &win {
+                    prop_assert!(win.team == team || !full_scan_has_win(&board, team));
+                }
+            }
+
+            let mut undone = board.clone();
+            undone.unpop(col, team).unwrap();
+            prop_assert_eq!(undone.position_hash(), before.position_hash());
+            prop_assert_eq!(undone, before);
+        }
+    }
+
+    #[test]
+    fn parts_rebuild_the_same_board((rows, cols, streak, moves) in game()) {
+        let mut board = Board::new(rows, cols, streak);
+        for (i, col) in moves.into_iter().enumerate() {
+            let _ = board.drop_piece(col, TEAMS[i % 2]);
+        }
+
+        let masks = board.masks().map(|(team, columns)| (team, columns.to_vec()));
+        let rebuilt = Board::from_parts(rows, cols, streak, masks, board.heights().to_vec());
+        prop_assert_eq!(rebuilt, Ok(board));
+    }
+
+    #[test]
+    fn solver_only_picks_legal_columns((rows, cols, streak, moves) in game()) {
+        let mut board = Board::new(rows, cols, streak);
+        for (i, col) in moves.into_iter().take(20).enumerate() {
+            if let Ok(played) = board.play(col, TEAMS[i % 2]) {
+                if played.outcome.is_some() {
+                    return Ok(());
+                }
+            }
+        }
+
+        let best = solver::best_columns(&board, TEAMS[0], TEAMS[1], 2);
+        prop_assert_eq!(best.is_empty(), board.is_full());
+        for col in best {
+            prop_assert!(board.legal_moves().any(|legal| legal == col));
+        }
+    }
+}
@@ -0,0 +1,288 @@
+//! Checks that the sender of a reducer may act on a room, a team or a game. The checks on rows are
+//! kept apart from the lookups so that they can be tested without a database.
+
+use std::fmt;
+
+use spacetimedb::{Identity, ReducerContext};
+
+use crate::{
+    game, join_room, join_team, room, team, Game, GameCurrentTeam, JoinRoom, JoinTeam, Room, Team,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum AuthError {
+    /// the sender has not joined any room
+    NotInRoom,
+    /// the sender is in a room but not in any of its teams
+    NotInTeam,
+    /// the sender watches the room and cannot play in it
+    Spectator,
+    /// the team or game belongs to another room than the sender's
+    OtherRoom,
+    NotRoomOwner,
+    TeamNotFound,
+    GameNotFound,
+    /// another team has to play
+    NotTeamTurn,
+}
+
+impl fmt::Display for AuthError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let message = match self {
+            AuthError::NotInRoom => "Not in a room",
+            AuthError::NotInTeam => "Not in a team",
+            AuthError::Spectator => "Spectators cannot play",
+            AuthError::OtherRoom => "Belongs to another room",
+            AuthError::NotRoomOwner => "Only the owner of the room can do this",
+            AuthError::TeamNotFound => "Team does not exist",
+            AuthError::GameNotFound => "Game does not exist",
+            AuthError::NotTeamTurn => "It's not your team's turn",
+        };
+        f.write_str(message)
+    }
+}
+
+impl From<AuthError> for String {
+    fn from(error: AuthError) -> Self {
+        error.to_string()
+    }
+}
+
+/// `room_id` is the room the member has joined
+pub(crate) fn ensure_same_room(jr: &JoinRoom, room_id: u32) -> Result<(), AuthError> {
+    if jr.room_id == room_id {
+        Ok(())
+    } else {
+        Err(AuthError::OtherRoom)
+    }
+}
+
+pub(crate) fn ensure_player(jr: &JoinRoom) -> Result<(), AuthError> {
+    if jr.spectator {
+        Err(AuthError::Spectator)
+    } else {
+        Ok(())
+    }
+}
+
+pub(crate) fn ensure_owner(room: &Room, sender: Identity) -> Result<(), AuthError> {
+    if room.owner == sender {
+        Ok(())
+    } else {
+        Err(AuthError::NotRoomOwner)
+    }
+}
+
+/// `jt` is the team membership of the member of `jr`, which must be a player of the same room
+pub(crate) fn ensure_team_member(jr: &JoinRoom, jt: &JoinTeam) -> Result<(), AuthError> {
+    ensure_player(jr)?;
+    ensure_same_room(jr, jt.room_id)
+}
+
+pub(crate) fn ensure_team_in_room(jr: &JoinRoom, team: &Team) -> Result<(), AuthError> {
+    ensure_same_room(jr, team.game_id)
+}
+
+pub(crate) fn ensure_turn(jt: &JoinTeam, gct: &GameCurrentTeam) -> Result<(), AuthError> {
+    if jt.room_id != gct.game_id {
+        Err(AuthError::OtherRoom)
+    } else if jt.team_id != gct.team_id {
+        Err(AuthError::NotTeamTurn)
+    } else {
+        Ok(())
+    }
+}
+
+/// Room membership of the sender
+pub(crate) fn room_member(ctx: &ReducerContext) -> Result<JoinRoom, AuthError> {
+    ctx.db
+        .join_room()
+        .joiner()
+        .find(ctx.sender)
+        .ok_or(AuthError::NotInRoom)
+}
+
+/// Team membership of the sender and the game of their room
+pub(crate) fn team_member(ctx: &ReducerContext) -> Result<(JoinTeam, Game), AuthError> {
+    let jr = room_member(ctx)?;
+    ensure_player(&jr)?;
+    let jt = ctx
+        .db
+        .join_team()
+        .joiner()
+        .find(ctx.sender)
+        .ok_or(AuthError::NotInTeam)?;
+    ensure_team_member(&jr, &jt)?;
+    let game = ctx
+        .db
+        .game()
+        .room_id()
+        .find(jt.room_id)
+        .ok_or(AuthError::GameNotFound)?;
+    Ok((jt, game))
+}
+
+/// Room of the sender when they own it
+pub(crate) fn room_owner(ctx: &ReducerContext) -> Result<Room, AuthError> {
+    let jr = room_member(ctx)?;
+    let room = ctx
+        .db
+        .room()
+        .id()
+        .find(jr.room_id)
+        .ok_or(AuthError::NotInRoom)?;
+    ensure_owner(&room, ctx.sender)?;
+    Ok(room)
+}
+
+/// Team `team_id` when it plays in the room of `jr`
+pub(crate) fn team_in_room(
+    ctx: &ReducerContext,
+    jr: &JoinRoom,
+    team_id: u32,
+) -> Result<Team, AuthError> {
+    let team = ctx
+        .db
+        .team()
+        .id()
+        .find(team_id)
+        .ok_or(AuthError::TeamNotFound)?;
+    ensure_team_in_room(jr, &team)?;
+    Ok(team)
+}
+
+#[cfg(test)]
+mod tests {
+    use spacetimedb::Timestamp;
+
+    use super::*;
+    use crate::{SpectatorJoin, StartPolicy};
+
+    const ALICE: Identity = Identity::ONE;
+    const MALLORY: Identity = Identity::from_byte_array([7; 32]);
+
+    fn join_room(joiner: Identity, room_id: u32, spectator: bool) -> JoinRoom {
+        JoinRoom {
+            room_id,
+            joiner,
+            joined_at: Timestamp::UNIX_EPOCH,
+            spectator,
+        }
+    }
+
+    fn join_team(joiner: Identity, room_id: u32, team_id: u32) -> JoinTeam {
+        JoinTeam {
+            room_id,
+            joiner,
+            team_id,
+            joined_at: Timestamp::UNIX_EPOCH,
+        }
+    }
+
+    fn team(id: u32, game_id: u32) -> Team {
+        Team {
+            id,
+            game_id,
+            name: "team".to_string(),
+            time_left: None,
+            bot: None,
+            eliminated: false,
+            last_dropper: None,
+            had_player: true,
+        }
+    }
+
+    fn current_team(game_id: u32, team_id: u32) -> GameCurrentTeam {
+        GameCurrentTeam {
+            game_id,
+            team_id,
+            turn_started_at: Timestamp::UNIX_EPOCH,
+            deadline: None,
+            dropper: None,
+            vote_deadline: None,
+        }
+    }
+
+    #[test]
+    fn team_of_another_room_is_rejected() {
+        let jr = join_room(MALLORY, 1, false);
+        assert_eq!(ensure_same_room(&jr, 1), Ok(()));
+        assert_eq!(ensure_same_room(&jr, 2), Err(AuthError::OtherRoom));
+    }
+
+    #[test]
+    fn joining_a_team_of_another_room_is_rejected() {
+        let jr = join_room(MALLORY, 2, false);
+        assert_eq!(ensure_team_in_room(&jr, &team(20, 2)), Ok(()));
+        assert_eq!(
+            ensure_team_in_room(&jr, &team(10, 1)),
+            Err(AuthError::OtherRoom)
+        );
+    }
+
+    #[test]
+    fn membership_left_behind_in_another_room_is_rejected() {
+        // Mallory moved to room 2 while their team membership still points to room 1, so they cannot
+        // play, vote or answer a takeback in room 1
+        let jr = join_room(MALLORY, 2, false);
+        let stale = join_team(MALLORY, 1, 10);
+        assert_eq!(ensure_team_member(&jr, &stale), Err(AuthError::OtherRoom));
+        assert_eq!(
+            ensure_team_member(&join_room(ALICE, 1, false), &join_team(ALICE, 1, 10)),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn spectators_of_the_room_cannot_act_for_a_team() {
+        let jr = join_room(MALLORY, 1, true);
+        assert_eq!(
+            ensure_team_member(&jr, &join_team(MALLORY, 1, 10)),
+            Err(AuthError::Spectator)
+        );
+    }
+
+    #[test]
+    fn spectators_cannot_play() {
+        assert_eq!(ensure_player(&join_room(ALICE, 1, false)), Ok(()));
+        assert_eq!(
+            ensure_player(&join_room(MALLORY, 1, true)),
+            Err(AuthError::Spectator)
+        );
+    }
+
+    #[test]
+    fn only_the_owner_administers_the_room() {
+        let room = Room {
+            id: 1,
+            title: "room".to_string(),
+            owner: ALICE,
+            created_at: Timestamp::UNIX_EPOCH,
+            spectators: 0,
+            spectator_join: SpectatorJoin::BetweenGames,
+            start_policy: StartPolicy::LoserStarts,
+        };
+        assert_eq!(ensure_owner(&room, ALICE), Ok(()));
+        assert_eq!(ensure_owner(&room, MALLORY), Err(AuthError::NotRoomOwner));
+    }
+
+    #[test]
+    fn turn_of_the_same_team_id_in_another_room_is_rejected() {
+        let gct = current_team(1, 10);
+        assert_eq!(ensure_turn(&join_team(ALICE, 1, 10), &gct), Ok(()));
+        assert_eq!(
+            ensure_turn(&join_team(ALICE, 1, 11), &gct),
+            Err(AuthError::NotTeamTurn)
+        );
+        assert_eq!(
+            ensure_turn(&join_team(MALLORY, 2, 10), &gct),
+            Err(AuthError::OtherRoom)
+        );
+    }
+
+    #[test]
+    fn errors_read_as_messages() {
+        let message: String = AuthError::OtherRoom.into();
+        assert_eq!(message, "Belongs to another room");
+    }
+}
@@ -0,0 +1,84 @@
+//! Column picking for the teams that are played by the module itself
+
+use conn4_rules::{solver, Board};
+use spacetimedb::rand::{seq::IteratorRandom, Rng};
+
+use crate::BotDifficulty;
+
+/// Bound on the work of a search, in board cells evaluated: the number of positions looked at times
+/// the size of the board. The classic board is searched 5 moves ahead within it.
+const SEARCH_BUDGET: usize = 1_000_000;
+
+impl BotDifficulty {
+    /// How many moves ahead the bot looks at most
+    fn max_depth(self) -> u32 {
+        match self {
+            BotDifficulty::Easy => 1,
+            BotDifficulty::Medium => 3,
+            BotDifficulty::Hard => 5,
+        }
+    }
+
+    /// Probability of playing a random column instead of the best one
+    fn blunder_chance(self) -> f64 {
+        match self {
+            BotDifficulty::Easy => 0.3,
+            BotDifficulty::Medium => 0.05,
+            BotDifficulty::Hard => 0.0,
+        }
+    }
+}
+
+/// Depth of the search on a board of `cells` cells where `moves` columns can be played, as deep as
+/// the difficulty allows while staying within the budget, so that big boards do not block the
+/// module
+fn search_depth(difficulty: BotDifficulty, moves: usize, cells: usize) -> u32 {
+    let mut depth = 1;
+    let mut work = moves.max(1) * cells;
+    while depth < difficulty.max_depth() {
+        work = work.saturating_mul(moves.max(1));
+        if work > SEARCH_BUDGET {
+            break;
+        }
+        depth += 1;
+    }
+    depth
+}
+
+/// Picks the column the bot drops its piece in, `None` if the board is full
+pub(crate) fn choose_column(
+    board: &Board,
+    bot_team_id: u32,
+    opponent_team_id: u32,
+    difficulty: BotDifficulty,
+    rng: &mut impl Rng,
+) -> Option<u32> {
+    let column = if rng.gen_bool(difficulty.blunder_chance()) {
+        board.legal_moves().choose(rng)
+    } else {
+        let depth = search_depth(
+            difficulty,
+            board.legal_moves().count(),
+            board.rows() * board.cols(),
+        );
+        solver::best_columns(board, bot_team_id, opponent_team_id, depth)
+            .into_iter()
+            .choose(rng)
+    };
+    column.map(|col| col as u32)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn search_is_shallower_on_big_boards() {
+        assert_eq!(search_depth(BotDifficulty::Hard, 7, 6 * 7), 5);
+        assert_eq!(search_depth(BotDifficulty::Easy, 7, 6 * 7), 1);
+        assert_eq!(search_depth(BotDifficulty::Hard, 30, 16 * 30), 2);
+        assert_eq!(search_depth(BotDifficulty::Medium, 30, 16 * 30), 2);
+        // a nearly full board can be searched deeper
+        assert_eq!(search_depth(BotDifficulty::Hard, 2, 16 * 30), 5);
+    }
+}
@@ -0,0 +1,398 @@
+//! Leaderboards of the players over several periods. When the points of a player change, only the
+//! players they pass, or that pass them, have their rank moved.
+
+use std::time::Duration;
+
+use spacetimedb::{
+    reducer, table, Identity, ReducerContext, ScheduleAt, SpacetimeType, Table, TimeDuration,
+    Timestamp,
+};
+
+use crate::{game_history, GameOutcome};
+
+const ONE_DAY_MICROS: i64 = 1_000_000 * 60 * 60 * 24;
+const SEASON_DURATION: Duration = Duration::from_secs(60 * 60 * 24 * 91);
+
+const BOARDS: [Leaderboard; 4] = [
+    Leaderboard::OneMonth,
+    Leaderboard::SixMonths,
+    Leaderboard::Season,
+    Leaderboard::AllTime,
+];
+
+/// Period of time the games of a leaderboard are counted over
+#[derive(SpacetimeType, Clone, Copy, PartialEq, Debug)]
+pub enum Leaderboard {
+    /// the last 30 days
+    OneMonth,
+    /// the last 6 months
+    SixMonths,
+    /// since the start of the current season
+    Season,
+    /// since the player's first game, kept when their history is purged
+    AllTime,
+}
+
+impl Leaderboard {
+    /// Number of days a game is counted for, `None` when it is counted until the board is reset
+    fn window(self) -> Option<u32> {
+        match self {
+            Leaderboard::OneMonth => Some(30),
+            Leaderboard::SixMonths => Some(182),
+            Leaderboard::Season | Leaderboard::AllTime => None,
+        }
+    }
+}
+
+/// Clients subscribe to the top of the leaderboards with `WHERE rank <= N`
+#[table(
+    name = leaderboard_entry,
+    public,
+    index(name = board_player, btree(columns = [board, player])),
+    index(name = board_points, btree(columns = [board, points]))
+)]
+pub struct LeaderboardEntry {
+    #[primary_key]
+    #[auto_inc]
+    id: u64,
+    board: Leaderboard,
+    player: Identity,
+    /// 1 for the most points, players with as many points share their rank
+    #[index(btree)]
+    rank: u32,
+    /// 2 for a win and 1 for a draw
+    points: u32,
+    wins: u32,
+    draws: u32,
+    games: u32,
+    /// wins per game played
+    win_rate: f32,
+}
+
+#[derive(Default, Clone, Copy)]
+struct Tally {
+    wins: u32,
+    draws: u32,
+    games: u32,
+}
+
+impl Tally {
+    fn add(&mut self, outcome: &GameOutcome) {
+        self.games += 1;
+        match outcome {
+            GameOutcome::Win => self.wins += 1,
+            GameOutcome::Draw => self.draws += 1,
+            _ => {}
+        }
+    }
+
+    fn remove(&mut self, outcome: &GameOutcome) {
+        self.games = self.games.saturating_sub(1);
+        match outcome {
+            GameOutcome::Win => self.wins = self.wins.saturating_sub(1),
+            GameOutcome::Draw => self.draws = self.draws.saturating_sub(1),
+            _ => {}
+        }
+    }
+
+    fn points(&self) -> u32 {
+        2 * self.wins + self.draws
+    }
+}
+
+fn find_entry(
+    ctx: &ReducerContext,
+    board: Leaderboard,
+    player: Identity,
+) -> Option<LeaderboardEntry> {
+    ctx.db
+        .leaderboard_entry()
+        .board_player()
+        .filter((board, player))
+        .next()
+}
+
+/// Days since the Unix epoch
+pub(crate) fn day_of(timestamp: Timestamp) -> u32 {
+    (timestamp.to_micros_since_unix_epoch() / ONE_DAY_MICROS) as u32
+}
+
+fn tally_of(entry: &LeaderboardEntry) -> Tally {
+    Tally {
+        wins: entry.wins,
+        draws: entry.draws,
+        games: entry.games,
+    }
+}
+
+/// Counts the game the player has just finished on every board
+pub(crate) fn record_game(
+    ctx: &ReducerContext,
+    player: Identity,
+    outcome: &GameOutcome,
+) -> Result<(), String> {
+    for board in BOARDS {
+        let mut tally = find_entry(ctx, board, player)
+            .map(|entry| tally_of(&entry))
+            .unwrap_or_default();
+        tally.add(outcome);
+        set_tally(ctx, board, player, tally)?;
+    }
+    Ok(())
+}
+
+/// A new entry without points, ranked with the other players without points
+fn insert_entry(
+    ctx: &ReducerContext,
+    board: Leaderboard,
+    player: Identity,
+) -> Result<LeaderboardEntry, String> {
+    let rank = match ctx
+        .db
+        .leaderboard_entry()
+        .board_points()
+        .filter((board, 0u32))
+        .next()
+    {
+        Some(entry) => entry.rank,
+        None => {
+            ctx.db
+                .leaderboard_entry()
+                .board_player()
+                .filter(board)
+                .count() as u32
+                + 1
+        }
+    };
+    Ok(ctx.db.leaderboard_entry().try_insert(LeaderboardEntry {
+        id: 0,
+        board,
+        player,
+        rank,
+        points: 0,
+        wins: 0,
+        draws: 0,
+        games: 0,
+        win_rate: 0.0,
+    })?)
+}
+
+/// Changes of the rank of a player whose points go from `old` to `new`, and of another player with
+/// `other` points. The rank of a player is 1 + the number of players with strictly more points.
+fn rank_changes(old: u32, new: u32, other: u32) -> (i32, i32) {
+    let was_above = (other > old) as i32;
+    let is_above = (other > new) as i32;
+    let was_below = (old > other) as i32;
+    let is_below = (new > other) as i32;
+    (is_above - was_above, is_below - was_below)
+}
+
+fn set_tally(
+    ctx: &ReducerContext,
+    board: Leaderboard,
+    player: Identity,
+    tally: Tally,
+) -> Result<(), String> {
+    let entry = match find_entry(ctx, board, player) {
+        Some(entry) => entry,
+        None => insert_entry(ctx, board, player)?,
+    };
+    let old = entry.points;
+    let new = tally.points();
+
+    let mut rank = entry.rank;
+    let between: Vec<_> = ctx
+        .db
+        .leaderboard_entry()
+        .board_points()
+        .filter((board, old.min(new)..=old.max(new)))
+        .filter(|other| other.id != entry.id)
+        .collect();
+    for other in between {
+        let (own_change, other_change) = rank_changes(old, new, other.points);
+        rank = rank.saturating_add_signed(own_change);
+        if other_change != 0 {
+            ctx.db.leaderboard_entry().id().update(LeaderboardEntry {
+                rank: other.rank.saturating_add_signed(other_change),
+                ..other
+            });
+        }
+    }
+
+    ctx.db.leaderboard_entry().id().update(LeaderboardEntry {
+        rank,
+        points: new,
+        wins: tally.wins,
+        draws: tally.draws,
+        games: tally.games,
+        win_rate: if tally.games == 0 {
+            0.0
+        } else {
+            tally.wins as f32 / tally.games as f32
+        },
+        ..entry
+    });
+    if tally.games == 0 {
+        // left with no points, so no other rank depends on it
+        ctx.db.leaderboard_entry().id().delete(entry.id);
+    }
+    Ok(())
+}
+
+/// Last day whose games have been removed from a board with a window
+#[table(name = leaderboard_expiry)]
+pub struct LeaderboardExpiry {
+    #[primary_key]
+    board: Leaderboard,
+    expired_through: u32,
+}
+
+#[spacetimedb::table(name = leaderboard_expiry_timer, scheduled(expire_leaderboards))]
+pub struct LeaderboardExpiryTimer {
+    #[primary_key]
+    #[auto_inc]
+    scheduled_id: u64,
+    scheduled_at: spacetimedb::ScheduleAt,
+}
+
+pub(crate) fn schedule_expiry(ctx: &ReducerContext) -> Result<(), String> {
+    ctx.db
+        .leaderboard_expiry_timer()
+        .try_insert(LeaderboardExpiryTimer {
+            scheduled_id: 0,
+            scheduled_at: ScheduleAt::Interval(TimeDuration::from_duration(Duration::from_secs(
+                60 * 60,
+            ))),
+        })?;
+    Ok(())
+}
+
+/// Removes the games that have left the window of a board, found by the day they were played on,
+/// so that the players who stopped playing drop down the board too
+#[reducer]
+fn expire_leaderboards(ctx: &ReducerContext, _timer: LeaderboardExpiryTimer) -> Result<(), String> {
+    if ctx.sender != ctx.identity() {
+        return Err(
+            "Reducer `expire_leaderboards` may only be invoked by the scheduler".to_string(),
+        );
+    }
+    let today = day_of(ctx.timestamp);
+    for board in BOARDS {
+        let Some(window) = board.window() else {
+            continue;
+        };
+        let last_expired = today.saturating_sub(window);
+        let Some(expiry) = ctx.db.leaderboard_expiry().board().find(board) else {
+            ctx.db.leaderboard_expiry().try_insert(LeaderboardExpiry {
+                board,
+                expired_through: last_expired,
+            })?;
+            continue;
+        };
+        if expiry.expired_through >= last_expired {
+            continue;
+        }
+
+        for history in ctx
+            .db
+            .game_history()
+            .day()
+            .filter(expiry.expired_through + 1..=last_expired)
+            .filter(|history| history.counted)
+        {
+            let Some(entry) = find_entry(ctx, board, history.player) else {
+                continue;
+            };
+            let mut tally = tally_of(&entry);
+            tally.remove(&history.outcome);
+            set_tally(ctx, board, history.player, tally)?;
+        }
+        ctx.db
+            .leaderboard_expiry()
+            .board()
+            .update(LeaderboardExpiry {
+                expired_through: last_expired,
+                ..expiry
+            });
+    }
+    Ok(())
+}
+
+#[table(name = season, public)]
+pub struct Season {
+    #[primary_key]
+    #[auto_inc]
+    id: u32,
+    started_at: Timestamp,
+    ends_at: Timestamp,
+}
+
+#[spacetimedb::table(name = season_timer, scheduled(end_season))]
+pub struct SeasonTimer {
+    #[primary_key]
+    #[auto_inc]
+    scheduled_id: u64,
+    scheduled_at: spacetimedb::ScheduleAt,
+    season_id: u32,
+}
+
+pub(crate) fn start_season(ctx: &ReducerContext) -> Result<(), String> {
+    let season = ctx.db.season().try_insert(Season {
+        id: 0,
+        started_at: ctx.timestamp,
+        ends_at: ctx.timestamp + SEASON_DURATION,
+    })?;
+    ctx.db.season_timer().try_insert(SeasonTimer {
+        scheduled_id: 0,
+        scheduled_at: ScheduleAt::Time(season.ends_at),
+        season_id: season.id,
+    })?;
+    Ok(())
+}
+
+/// Clears the season leaderboard and starts the next season
+#[reducer]
+fn end_season(ctx: &ReducerContext, timer: SeasonTimer) -> Result<(), String> {
+    if ctx.sender != ctx.identity() {
+        return Err("Reducer `end_season` may only be invoked by the scheduler".to_string());
+    }
+    log::info!("Season {} has ended", timer.season_id);
+    ctx.db
+        .leaderboard_entry()
+        .board_player()
+        .delete(Leaderboard::Season);
+    start_season(ctx)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ranks(points: &[u32]) -> Vec<u32> {
+        points
+            .iter()
+            .map(|p| 1 + points.iter().filter(|other| *other > p).count() as u32)
+            .collect()
+    }
+
+    #[test]
+    fn moving_ranks_matches_ranking_from_scratch() {
+        let before = [0, 2, 4, 4, 6, 9];
+        for player in 0..before.len() {
+            for new in 0..12 {
+                let mut after = before;
+                after[player] = new;
+                let mut moved = ranks(&before);
+                for (other, &points) in before.iter().enumerate() {
+                    if other == player {
+                        continue;
+                    }
+                    let (own, theirs) = rank_changes(before[player], new, points);
+                    moved[player] = moved[player].saturating_add_signed(own);
+                    moved[other] = moved[other].saturating_add_signed(theirs);
+                }
+                assert_eq!(moved, ranks(&after), "player {} to {} points", player, new);
+            }
+        }
+    }
+}
@@ -1,19 +1,39 @@
 use std::time::Duration;
 
+use conn4_rules::{Board, Outcome};
 use log::info;
 use spacetimedb::{
     client_visibility_filter,
-    rand::{seq::IteratorRandom, Rng},
+    rand::seq::{IteratorRandom, SliceRandom},
     reducer, table, Filter, Identity, ReducerContext, ScheduleAt, SpacetimeType, Table,
     TimeDuration, Timestamp,
 };
 use unicode_segmentation::UnicodeSegmentation;
 
-const STREAK_REQUIRED: usize = 4;
-
-const ROWS: usize = 6;
-/// Must be >= `STREAK_REQUIRED`` to not cause panic when checking for a win
-const COLS: usize = 20;
+mod auth;
+mod bot;
+mod leaderboard;
+mod rating;
+mod skin;
+mod wallet;
+
+const MIN_ROWS: u32 = 4;
+const MAX_ROWS: u32 = 16;
+const MIN_COLS: u32 = 4;
+const MAX_COLS: u32 = 30;
+const MIN_STREAK: u32 = 3;
+const MAX_STREAK: u32 = 8;
+const MIN_TURN_SECONDS: u32 = 5;
+const MAX_TURN_SECONDS: u32 = 60 * 60;
+const MIN_TOTAL_SECONDS: u32 = 30;
+const MAX_TOTAL_SECONDS: u32 = 60 * 60 * 2;
+const MIN_TEAMS: u32 = 2;
+const MAX_TEAMS: u32 = 4;
+/// How long the members of a team have to vote once the first vote of the turn is cast
+const VOTE_DURATION: Duration = Duration::from_secs(20);
+/// How long the players have to vote for a rematch once the first vote is cast, the rematch starts
+/// with the votes there are when it runs out
+const REMATCH_TIMEOUT: Duration = Duration::from_secs(30);
 
 #[table(name = player, public)]
 pub struct Player {
@@ -21,6 +41,8 @@ pub struct Player {
     identity: Identity,
     name: Option<String>,
     online: bool,
+    /// skin of the pieces the player drops, the default coin if `None`
+    skin: Option<u32>,
 }
 
 #[table(name = join_team, public)]
@@ -30,7 +52,10 @@ pub struct JoinTeam {
     room_id: u32,
     #[primary_key]
     joiner: Identity,
+    #[index(btree)]
     team_id: u32,
+    /// when the player joined the team, members take turns dropping in this order
+    joined_at: Timestamp,
 }
 
 enum DeleteJoinTeamBy {
@@ -49,27 +74,209 @@ fn delete_join_team(ctx: &ReducerContext, by: DeleteJoinTeamBy) {
     }
 }
 
-#[derive(SpacetimeType)]
+#[derive(SpacetimeType, Clone)]
 struct Coord {
     x: u32,
     y: u32,
 }
 
-#[derive(SpacetimeType)]
+#[derive(SpacetimeType, Clone)]
 struct Winner {
     team_id: u32,
     coordinates: Vec<Coord>, // cells that are part of the winning line
 }
 
+/// Pieces of one team, one word per column where bit `i` is the `i`-th piece from the bottom
 #[derive(SpacetimeType, Clone)]
-struct DroppedPiece {
+struct TeamMask {
     team_id: u32,
-    dropper: Identity,
+    columns: Vec<u64>,
+}
+
+/// Board of a game stored as bitboards, one mask per team that has dropped a piece
+#[derive(SpacetimeType, Clone)]
+struct GameBoard {
+    masks: Vec<TeamMask>,
+    /// number of pieces in each column
+    heights: Vec<u8>,
+}
+
+impl GameBoard {
+    fn empty(settings: &GameSettings) -> Self {
+        Self {
+            masks: vec![],
+            heights: vec![0; settings.cols as usize],
+        }
+    }
+
+    fn to_board(&self, settings: &GameSettings) -> Result<Board, String> {
+        Board::from_parts(
+            settings.rows as usize,
+            settings.cols as usize,
+            settings.streak as usize,
+            self.masks
+                .iter()
+                .map(|mask| (mask.team_id, mask.columns.clone())),
+            self.heights.clone(),
+        )
+        .map_err(|e| format!("Cannot load the board of the game: {}", e))
+    }
+}
+
+impl From<&Board> for GameBoard {
+    fn from(board: &Board) -> Self {
+        Self {
+            masks: board
+                .masks()
+                .map(|(team_id, columns)| TeamMask {
+                    team_id,
+                    columns: columns.to_vec(),
+                })
+                .collect(),
+            heights: board.heights().to_vec(),
+        }
+    }
+}
+
+/// What happens to a team that has not dropped a piece before the end of its turn
+#[derive(SpacetimeType, Clone, Copy, PartialEq)]
+enum TimeoutAction {
+    /// the turn goes to the next team
+    SkipTurn,
+    /// a piece is dropped in a random column that is not full
+    RandomMove,
+    /// the team loses the game
+    Forfeit,
+}
+
+/// Who of the team whose turn it is may drop the piece
+#[derive(SpacetimeType, Clone, Copy, PartialEq)]
+enum DropMode {
+    /// any member of the team
+    Anyone,
+    /// members take turns in the order they joined the team, skipping the ones that are offline
+    Rotation,
+    /// members vote on a column and the most voted one is played
+    Vote,
+}
+
+/// Rules the game is played with
+#[derive(SpacetimeType, Clone, Copy, PartialEq)]
+enum Variant {
+    Classic,
+    /// a team may also pop one of its pieces out of the bottom row instead of dropping one. A full
+    /// board does not end the game, but a position reached for the third time does.
+    PopOut,
+}
+
+/// How a move changed the board
+#[derive(SpacetimeType, Clone, Copy, PartialEq)]
+enum MoveKind {
+    /// a piece was dropped on top of a column
+    Drop,
+    /// a piece was removed from the bottom of a column, shifting it down
+    Pop,
 }
 
-type Cell = Option<DroppedPiece>;
+/// How hard the module plays when it controls a team
+#[derive(SpacetimeType, Clone, Copy, PartialEq)]
+enum BotDifficulty {
+    Easy,
+    Medium,
+    Hard,
+}
+
+/// Shape of the board, the length of the line needed to win, the number of teams and the time
+/// limits, chosen when the game is created
+#[derive(SpacetimeType, Clone)]
+pub struct GameSettings {
+    rows: u32,
+    cols: u32,
+    /// Must be <= `rows` and <= `cols` to not cause panic when checking for a win
+    streak: u32,
+    /// number of teams taking turns
+    teams: u32,
+    /// number of players a team can have, unlimited if `None`
+    max_team_size: Option<u32>,
+    /// percentage of the players in teams that must vote for a rematch before the board is reset
+    rematch_quorum: u32,
+    /// moves cannot be taken back and the result changes the ratings of the players
+    ranked: bool,
+    variant: Variant,
+    /// time a team has for each of its turns, unlimited if `None`
+    turn_seconds: Option<u32>,
+    /// time a team has for all of its turns in a round, chess clock style, unlimited if `None`.
+    /// Running out of it always forfeits the game.
+    total_seconds: Option<u32>,
+    timeout_action: TimeoutAction,
+    /// one of the teams is played by the module when set
+    bot: Option<BotDifficulty>,
+    drop_mode: DropMode,
+}
 
-type GameTable = Vec<Vec<Cell>>;
+impl GameSettings {
+    fn validate(&self) -> Result<(), String> {
+        if !(MIN_ROWS..=MAX_ROWS).contains(&self.rows) {
+            return Err(format!(
+                "Rows must be between {} and {}",
+                MIN_ROWS, MAX_ROWS
+            ));
+        }
+        if !(MIN_COLS..=MAX_COLS).contains(&self.cols) {
+            return Err(format!(
+                "Columns must be between {} and {}",
+                MIN_COLS, MAX_COLS
+            ));
+        }
+        if !(MIN_STREAK..=MAX_STREAK).contains(&self.streak) {
+            return Err(format!(
+                "Streak must be between {} and {}",
+                MIN_STREAK, MAX_STREAK
+            ));
+        }
+        if self.streak > self.rows || self.streak > self.cols {
+            return Err("Streak must not be longer than the rows or the columns".to_string());
+        }
+        if !(MIN_TEAMS..=MAX_TEAMS).contains(&self.teams) {
+            return Err(format!(
+                "Teams must be between {} and {}",
+                MIN_TEAMS, MAX_TEAMS
+            ));
+        }
+        if self.max_team_size == Some(0) {
+            return Err("Max team size must be at least 1".to_string());
+        }
+        if !(1..=100).contains(&self.rematch_quorum) {
+            return Err("Rematch quorum must be between 1 and 100 percent".to_string());
+        }
+        // votes are only for the column to drop a piece in
+        if self.variant == Variant::PopOut && self.drop_mode == DropMode::Vote {
+            return Err("Pop Out cannot be played by voting".to_string());
+        }
+        if self.ranked && self.bot.is_some() {
+            return Err("Games against a bot cannot be ranked".to_string());
+        }
+        if self
+            .turn_seconds
+            .is_some_and(|secs| !(MIN_TURN_SECONDS..=MAX_TURN_SECONDS).contains(&secs))
+        {
+            return Err(format!(
+                "Turn time must be between {} and {} seconds",
+                MIN_TURN_SECONDS, MAX_TURN_SECONDS
+            ));
+        }
+        if self
+            .total_seconds
+            .is_some_and(|secs| !(MIN_TOTAL_SECONDS..=MAX_TOTAL_SECONDS).contains(&secs))
+        {
+            return Err(format!(
+                "Total time must be between {} and {} seconds",
+                MIN_TOTAL_SECONDS, MAX_TOTAL_SECONDS
+            ));
+        }
+        Ok(())
+    }
+}
 
 #[table(name = team, public)]
 pub struct Team {
@@ -79,6 +286,16 @@ pub struct Team {
     #[index(btree)]
     game_id: u32,
     name: String,
+    /// time left on the team's clock when the game has a total time limit
+    time_left: Option<TimeDuration>,
+    /// the team is played by the module, players cannot join it
+    bot: Option<BotDifficulty>,
+    /// the team has forfeited while other teams keep playing, its turns are skipped
+    eliminated: bool,
+    /// member that had the last turn to drop a piece for the team, the rotation goes on from them
+    last_dropper: Option<Identity>,
+    /// a player has been on the team during the current round
+    had_player: bool,
 }
 
 enum DeleteTeamBy {
@@ -99,6 +316,14 @@ pub struct GameCurrentTeam {
     game_id: u32,
     #[unique]
     team_id: u32,
+    turn_started_at: Timestamp,
+    /// when the turn of the team runs out, `None` if the turn is not timed
+    deadline: Option<Timestamp>,
+    /// member of the team that has to drop the piece when the game uses `DropMode::Rotation`
+    dropper: Option<Identity>,
+    /// when the votes of the team are counted when the game uses `DropMode::Vote`, set by the
+    /// first vote of the turn
+    vote_deadline: Option<Timestamp>,
 }
 
 enum DeleteGameCurrentTeamBy {
@@ -113,26 +338,86 @@ fn delete_game_current_team(ctx: &ReducerContext, by: DeleteGameCurrentTeamBy) {
     }
 }
 
+/// Column a member of the team whose turn it is wants to play, when the game uses `DropMode::Vote`
+#[table(name = team_vote, public)]
+pub struct TeamVote {
+    #[primary_key]
+    voter: Identity,
+    #[index(btree)]
+    game_id: u32,
+    #[index(btree)]
+    team_id: u32,
+    column: u32,
+    voted_at: Timestamp,
+}
+
+/// A player can only see the votes of their own team
+#[client_visibility_filter]
+const TEAM_VOTE_FILTER: Filter = Filter::Sql(
+    "SELECT team_vote.* FROM team_vote JOIN join_team ON team_vote.team_id = join_team.team_id WHERE join_team.joiner = :sender",
+);
+
+enum DeleteTeamVoteBy {
+    Voter(Identity),
+    GameId(u32),
+}
+
+fn delete_team_vote(ctx: &ReducerContext, by: DeleteTeamVoteBy) {
+    match by {
+        DeleteTeamVoteBy::Voter(voter) => {
+            ctx.db.team_vote().voter().delete(voter);
+        }
+        DeleteTeamVoteBy::GameId(game_id) => {
+            ctx.db.team_vote().game_id().delete(game_id);
+        }
+    }
+}
+
 #[table(name = game, public)]
 pub struct Game {
     #[primary_key]
     room_id: u32,
 
+    /// increases every time the game is restarted, moves of a round are stored in `game_move`
+    round: u32,
+    /// board size and streak the game is played with
+    settings: GameSettings,
+    /// ids of the teams in the order they take turns
+    turn_order: Vec<u32>,
+    /// team that played first in this round
+    starting_team_id: u32,
     /// player that has won the game
     winner: Option<Winner>,
-    /// table of the game
-    table: GameTable,
+    /// pieces on the board, the droppers are kept in `game_move`
+    board: GameBoard,
     /// last move made by a player
     latest_move: Option<Coord>,
+    /// the round has ended without a winner
+    drawn: bool,
+    /// keys of the positions reached in this round, for the repetition rule of `Variant::PopOut`
+    positions: Vec<u64>,
 }
 
 impl Game {
-    fn new(room_id: u32) -> Self {
+    fn new(
+        room_id: u32,
+        round: u32,
+        settings: GameSettings,
+        turn_order: Vec<u32>,
+        starting_team_id: u32,
+    ) -> Self {
+        let board = GameBoard::empty(&settings);
         Self {
-            room_id: room_id,
+            room_id,
+            round,
+            settings,
+            turn_order,
+            starting_team_id,
             winner: None,
-            table: vec![vec![None; COLS]; ROWS],
+            board,
             latest_move: None,
+            drawn: false,
+            positions: vec![],
         }
     }
 }
@@ -147,16 +432,271 @@ fn delete_game(ctx: &ReducerContext, by: DeleteGameBy) {
             ctx.db.game().room_id().delete(room_id);
             delete_team(ctx, DeleteTeamBy::GameId(room_id));
             delete_game_current_team(ctx, DeleteGameCurrentTeamBy::GameId(room_id));
+            delete_team_vote(ctx, DeleteTeamVoteBy::GameId(room_id));
+            delete_rematch_vote(ctx, DeleteRematchVoteBy::GameId(room_id));
+            ctx.db.takeback_request().game_id().delete(room_id);
         }
     }
 }
 
 impl Game {
-    fn is_table_full(&self) -> bool {
-        self.table
-            .iter()
-            .all(|row| row.iter().all(|cell| cell.is_some()))
+    /// Someone has won or the round is drawn
+    fn is_over(&self) -> bool {
+        self.winner.is_some() || self.drawn
+    }
+
+    /// At least one piece has been dropped and the game has not ended yet
+    fn is_in_progress(&self) -> bool {
+        self.latest_move.is_some() && !self.is_over()
+    }
+}
+
+/// Number of times a position has to be reached for a `Variant::PopOut` round to be drawn
+const REPETITIONS_FOR_DRAW: usize = 3;
+
+/// Identifies the board after `team_id` has moved, two positions with the same key are repeated
+fn position_key(board: &Board, team_id: u32) -> u64 {
+    board.position_hash() ^ (team_id as u64).rotate_left(32)
+}
+
+/// A piece dropped or popped during a round of a game, in the order it was played
+#[table(name = game_move, public, index(name = game_round, btree(columns = [game_id, round])))]
+pub struct GameMove {
+    #[primary_key]
+    #[auto_inc]
+    id: u64,
+    game_id: u32,
+    round: u32,
+    /// 0 for the first move of the round
+    ply: u32,
+    kind: MoveKind,
+    column: u32,
+    /// row the piece landed on, or the bottom row for a pop
+    row: u32,
+    team_id: u32,
+    dropper: Identity,
+    /// skin the dropper had equipped
+    skin: Option<u32>,
+    timestamp: Timestamp,
+}
+
+/// Moves of a round sorted by ply
+fn round_moves(ctx: &ReducerContext, game_id: u32, round: u32) -> Vec<GameMove> {
+    let mut moves: Vec<GameMove> = ctx
+        .db
+        .game_move()
+        .game_round()
+        .filter((game_id, round))
+        .collect();
+    moves.sort_by_key(|m| m.ply);
+    moves
+}
+
+/// Round to start a new game with, so that its moves are not mixed with moves of a previous game
+/// that was played in the same room
+fn first_free_round(ctx: &ReducerContext, game_id: u32) -> u32 {
+    ctx.db
+        .game_move()
+        .game_round()
+        .filter(game_id)
+        .map(|m| m.round + 1)
+        .max()
+        .unwrap_or(0)
+}
+
+/// Rebuilds a board by replaying the `(kind, column, team_id)` of its moves
+fn replay_board(
+    settings: &GameSettings,
+    moves: impl IntoIterator<Item = (MoveKind, u32, u32)>,
+) -> Result<Board, String> {
+    let mut board = Board::new(
+        settings.rows as usize,
+        settings.cols as usize,
+        settings.streak as usize,
+    );
+    for (kind, column, team_id) in moves {
+        let result = match kind {
+            MoveKind::Drop => board.drop_piece(column as usize, team_id).map(|_| ()),
+            MoveKind::Pop => board.pop_piece(column as usize, team_id),
+        };
+        result.map_err(|e| format!("Cannot replay the moves: {}", e))?;
+    }
+    Ok(board)
+}
+
+#[derive(SpacetimeType)]
+struct ArchivedTeam {
+    team_id: u32,
+    name: String,
+    /// players that were in the team when the game ended
+    members: Vec<Identity>,
+}
+
+#[derive(SpacetimeType)]
+struct ArchivedMove {
+    kind: MoveKind,
+    column: u32,
+    row: u32,
+    team_id: u32,
+    dropper: Identity,
+    skin: Option<u32>,
+    timestamp: Timestamp,
+}
+
+/// A finished game kept for replays after the room has moved on to the next round
+#[table(name = archived_game, public)]
+pub struct ArchivedGame {
+    #[primary_key]
+    #[auto_inc]
+    id: u32,
+    #[index(btree)]
+    room_id: u32,
+    round: u32,
+    settings: GameSettings,
+    teams: Vec<ArchivedTeam>,
+    winner: Option<Winner>,
+    /// final board of the game
+    board: GameBoard,
+    /// moves sorted by ply
+    moves: Vec<ArchivedMove>,
+    starting_team_id: u32,
+    ended_at: Timestamp,
+}
+
+fn archive_game(ctx: &ReducerContext, game: &Game) -> Result<ArchivedGame, String> {
+    let teams = ctx
+        .db
+        .team()
+        .game_id()
+        .filter(game.room_id)
+        .map(|team| ArchivedTeam {
+            team_id: team.id,
+            members: ctx
+                .db
+                .join_team()
+                .room_id()
+                .filter(game.room_id)
+                .filter(|jt| jt.team_id == team.id)
+                .map(|jt| jt.joiner)
+                .collect(),
+            name: team.name,
+        })
+        .collect();
+    let moves = round_moves(ctx, game.room_id, game.round)
+        .into_iter()
+        .map(|m| ArchivedMove {
+            kind: m.kind,
+            column: m.column,
+            row: m.row,
+            team_id: m.team_id,
+            dropper: m.dropper,
+            skin: m.skin,
+            timestamp: m.timestamp,
+        })
+        .collect();
+
+    Ok(ctx.db.archived_game().try_insert(ArchivedGame {
+        id: 0,
+        room_id: game.room_id,
+        round: game.round,
+        settings: game.settings.clone(),
+        teams,
+        winner: game.winner.clone(),
+        board: game.board.clone(),
+        moves,
+        starting_team_id: game.starting_team_id,
+        ended_at: ctx.timestamp,
+    })?)
+}
+
+/// A player stepping through an archived game, one replay at a time per player
+#[table(name = game_replay, public)]
+pub struct GameReplay {
+    #[primary_key]
+    viewer: Identity,
+    archive_id: u32,
+    /// number of moves of the archived game that are on `board`
+    ply: u32,
+    board: GameBoard,
+    latest_move: Option<Coord>,
+}
+
+/// A player can only see their own replay
+#[client_visibility_filter]
+const GAME_REPLAY_FILTER: Filter = Filter::Sql("SELECT * FROM game_replay WHERE viewer = :sender");
+
+fn replay_at(archive: &ArchivedGame, ply: u32, viewer: Identity) -> Result<GameReplay, String> {
+    let moves = &archive.moves[..ply as usize];
+    let board = replay_board(
+        &archive.settings,
+        moves.iter().map(|m| (m.kind, m.column, m.team_id)),
+    )?;
+    Ok(GameReplay {
+        viewer,
+        archive_id: archive.id,
+        ply,
+        board: GameBoard::from(&board),
+        latest_move: moves.last().map(|m| Coord {
+            x: m.row,
+            y: m.column,
+        }),
+    })
+}
+
+#[reducer]
+pub fn open_replay(ctx: &ReducerContext, archive_id: u32) -> Result<(), String> {
+    let Some(archive) = ctx.db.archived_game().id().find(archive_id) else {
+        return Err("Archived game not found".to_string());
+    };
+
+    let replay = replay_at(&archive, 0, ctx.sender)?;
+    if ctx.db.game_replay().viewer().find(ctx.sender).is_some() {
+        ctx.db.game_replay().viewer().update(replay);
+    } else {
+        ctx.db.game_replay().try_insert(replay)?;
+    }
+
+    Ok(())
+}
+
+/// Moves the replay of the sender to the position after `ply` moves
+#[reducer]
+pub fn step_replay(ctx: &ReducerContext, ply: u32) -> Result<(), String> {
+    let Some(replay) = ctx.db.game_replay().viewer().find(ctx.sender) else {
+        return Err("Cannot step through a replay that is not open".to_string());
+    };
+
+    let Some(archive) = ctx.db.archived_game().id().find(replay.archive_id) else {
+        return Err("Archived game not found".to_string());
+    };
+
+    if ply as usize > archive.moves.len() {
+        return Err("Ply out of bounds".to_string());
     }
+
+    ctx.db
+        .game_replay()
+        .viewer()
+        .update(replay_at(&archive, ply, ctx.sender)?);
+
+    Ok(())
+}
+
+#[reducer]
+pub fn close_replay(ctx: &ReducerContext) {
+    ctx.db.game_replay().viewer().delete(ctx.sender);
+}
+
+#[derive(SpacetimeType, Clone, Copy, PartialEq)]
+enum GameOutcome {
+    Win,
+    Loss,
+    /// the table was filled without a winner
+    Draw,
+    /// the player's team gave up or left the game before it ended
+    Forfeit,
+    /// every team left the game before it ended
+    Abandoned,
 }
 
 #[table(name = game_history, public)]
@@ -166,8 +706,17 @@ pub struct GameHistory {
     id: u32,
     #[index(btree)]
     player: Identity,
-    won: bool,
+    /// game that can be replayed from `archived_game`
+    archive_id: u32,
+    outcome: GameOutcome,
+    /// the team of the player dropped the first piece
+    went_first: bool,
     timestamp: Timestamp,
+    /// day of `timestamp`, to find the games leaving the window of a leaderboard
+    #[index(btree)]
+    day: u32,
+    /// the game is on the leaderboards, every other team had a player during the round
+    counted: bool,
 }
 
 /// A player can only see their own game history
@@ -198,14 +747,189 @@ fn auto_delete_game_history(ctx: &ReducerContext, _timer: AutoDeleteGameHistoryT
             )
         }
     }
+    for m in ctx.db.game_move().iter() {
+        if ctx
+            .timestamp
+            .duration_since(m.timestamp)
+            .is_some_and(|elapsed| elapsed > one_year)
+        {
+            ctx.db.game_move().id().delete(m.id);
+        }
+    }
+    for archive in ctx.db.archived_game().iter() {
+        if ctx
+            .timestamp
+            .duration_since(archive.ended_at)
+            .is_some_and(|elapsed| elapsed > one_year)
+        {
+            ctx.db.archived_game().id().delete(archive.id);
+        }
+    }
 }
 
-#[table(name = stats_one_month, public)]
-pub struct StatsOneMonth {
+/// Glicko-2 rating of a player, changed by ranked games
+#[table(name = player_rating, public)]
+pub struct PlayerRating {
     #[primary_key]
     player: Identity,
-    wins: u32,
-    total: u32,
+    rating: f64,
+    deviation: f64,
+    volatility: f64,
+    /// number of rated games
+    games: u32,
+    last_played: Timestamp,
+}
+
+impl PlayerRating {
+    fn to_rating(&self) -> rating::Rating {
+        rating::Rating {
+            rating: self.rating,
+            deviation: self.deviation,
+            volatility: self.volatility,
+        }
+    }
+}
+
+fn rating_of(ctx: &ReducerContext, player: Identity) -> rating::Rating {
+    ctx.db
+        .player_rating()
+        .player()
+        .find(player)
+        .map(|r| r.to_rating())
+        .unwrap_or_default()
+}
+
+/// Rates the players of a ranked game. Every team plays against every other team, and ranks
+/// above it when it won, or when it was still in the game while the other team had forfeited.
+fn update_ratings(ctx: &ReducerContext, game: &Game, end: &GameEnd) -> Result<(), String> {
+    let mut teams: Vec<(u32, Vec<(Identity, rating::Rating)>)> = Vec::new();
+    for jt in ctx.db.join_team().room_id().filter(game.room_id) {
+        let rating = rating_of(ctx, jt.joiner);
+        match teams.iter_mut().find(|(team_id, _)| *team_id == jt.team_id) {
+            Some((_, members)) => members.push((jt.joiner, rating)),
+            None => teams.push((jt.team_id, vec![(jt.joiner, rating)])),
+        }
+    }
+
+    let place = |team_id: u32| {
+        let forfeited = matches!(end, GameEnd::Forfeit { team_id: id } if *id == team_id)
+            || ctx
+                .db
+                .team()
+                .id()
+                .find(team_id)
+                .is_some_and(|team| team.eliminated);
+        if game.winner.as_ref().is_some_and(|w| w.team_id == team_id) {
+            2
+        } else if forfeited {
+            0
+        } else {
+            1
+        }
+    };
+    let teams: Vec<_> = teams
+        .into_iter()
+        .map(|(team_id, members)| {
+            let ratings: Vec<_> = members.iter().map(|(_, r)| *r).collect();
+            (place(team_id), rating::team_rating(&ratings), members)
+        })
+        .collect();
+
+    for (i, (own_place, _, members)) in teams.iter().enumerate() {
+        let opponents: Vec<_> = teams
+            .iter()
+            .enumerate()
+            .filter(|(j, _)| *j != i)
+            .map(|(_, (place, team_rating, _))| {
+                let score = match own_place.cmp(place) {
+                    std::cmp::Ordering::Greater => 1.0,
+                    std::cmp::Ordering::Equal => 0.5,
+                    std::cmp::Ordering::Less => 0.0,
+                };
+                (*team_rating, score)
+            })
+            .collect();
+        if opponents.is_empty() {
+            continue;
+        }
+        for (player, rating) in members {
+            let rated = rating.rate(members.len(), &opponents);
+            if let Some(player_rating) = ctx.db.player_rating().player().find(*player) {
+                ctx.db.player_rating().player().update(PlayerRating {
+                    rating: rated.rating,
+                    deviation: rated.deviation,
+                    volatility: rated.volatility,
+                    games: player_rating.games + 1,
+                    last_played: ctx.timestamp,
+                    ..player_rating
+                });
+            } else {
+                ctx.db.player_rating().try_insert(PlayerRating {
+                    player: *player,
+                    rating: rated.rating,
+                    deviation: rated.deviation,
+                    volatility: rated.volatility,
+                    games: 1,
+                    last_played: ctx.timestamp,
+                })?;
+            }
+        }
+    }
+    Ok(())
+}
+
+#[spacetimedb::table(name = rating_decay_timer, scheduled(decay_ratings))]
+pub struct RatingDecayTimer {
+    #[primary_key]
+    scheduled_id: u64,
+    scheduled_at: spacetimedb::ScheduleAt,
+}
+
+/// Once a day, the deviation of the players that have not played that day grows
+#[reducer]
+fn decay_ratings(ctx: &ReducerContext, _timer: RatingDecayTimer) -> Result<(), String> {
+    if ctx.sender != ctx.identity() {
+        return Err("Reducer `decay_ratings` may only be invoked by the scheduler".to_string());
+    }
+    let one_day = Duration::from_secs(60 * 60 * 24);
+    for player_rating in ctx.db.player_rating().iter() {
+        if player_rating.deviation >= rating::MAX_DEVIATION
+            || ctx
+                .timestamp
+                .duration_since(player_rating.last_played)
+                .is_none_or(|elapsed| elapsed < one_day)
+        {
+            continue;
+        }
+        let decayed = player_rating.to_rating().decay();
+        ctx.db.player_rating().player().update(PlayerRating {
+            deviation: decayed.deviation,
+            ..player_rating
+        });
+    }
+    Ok(())
+}
+
+/// Which team starts a rematch
+#[derive(SpacetimeType, Clone, Copy, PartialEq)]
+pub enum StartPolicy {
+    /// the team after the one that started the previous round
+    Alternate,
+    /// a team that lost the previous round, alternating after a draw
+    LoserStarts,
+    /// any team that can play
+    Random,
+}
+
+/// When a member of a room who is not on a team may join one
+#[derive(SpacetimeType, Clone, Copy, PartialEq)]
+pub enum SpectatorJoin {
+    /// also while a game is in progress
+    Anytime,
+    /// only before the first piece is dropped or once the game has ended
+    BetweenGames,
+    /// spectators only watch, the other members join between games
+    Never,
 }
 
 #[table(name = room, public)]
@@ -217,6 +941,10 @@ pub struct Room {
     #[unique]
     owner: Identity,
     created_at: Timestamp,
+    /// number of players watching the room
+    spectators: u32,
+    spectator_join: SpectatorJoin,
+    start_policy: StartPolicy,
 }
 enum DeleteRoomBy {
     RoomId(u32),
@@ -243,8 +971,20 @@ pub struct Message {
     #[index(btree)]
     sent_at: Timestamp,
     text: String,
+    /// only the spectators of the room can read the message
+    spectators_only: bool,
 }
 
+/// Messages to the whole room can be read by everyone
+#[client_visibility_filter]
+const MESSAGE_FILTER: Filter = Filter::Sql("SELECT * FROM message WHERE spectators_only = false");
+
+/// Messages between spectators can only be read by the spectators of the room
+#[client_visibility_filter]
+const SPECTATOR_MESSAGE_FILTER: Filter = Filter::Sql(
+    "SELECT message.* FROM message JOIN join_room ON message.room_id = join_room.room_id WHERE join_room.joiner = :sender AND join_room.spectator = true",
+);
+
 enum DeleteMessageBy {
     RoomId(u32),
 }
@@ -264,6 +1004,8 @@ pub struct JoinRoom {
     #[primary_key]
     joiner: Identity,
     joined_at: Timestamp,
+    /// the player watches the room and is not in any team
+    spectator: bool,
 }
 
 enum DeleteJoinRoomBy {
@@ -274,7 +1016,10 @@ enum DeleteJoinRoomBy {
 fn delete_join_room(ctx: &ReducerContext, by: DeleteJoinRoomBy) {
     match by {
         DeleteJoinRoomBy::Joiner(joiner) => {
-            ctx.db.join_room().joiner().delete(joiner);
+            if let Some(jr) = ctx.db.join_room().joiner().find(joiner) {
+                ctx.db.join_room().joiner().delete(joiner);
+                update_spectator_count(ctx, jr.room_id);
+            }
         }
         DeleteJoinRoomBy::RoomId(room_id) => {
             ctx.db.join_room().room_id().delete(room_id);
@@ -282,6 +1027,35 @@ fn delete_join_room(ctx: &ReducerContext, by: DeleteJoinRoomBy) {
     }
 }
 
+fn update_spectator_count(ctx: &ReducerContext, room_id: u32) {
+    let Some(room) = ctx.db.room().id().find(room_id) else {
+        return;
+    };
+    let spectators = ctx
+        .db
+        .join_room()
+        .room_id()
+        .filter(room_id)
+        .filter(|jr| jr.spectator)
+        .count() as u32;
+    if spectators != room.spectators {
+        ctx.db.room().id().update(Room { spectators, ..room });
+    }
+}
+
+/// Switches a member of a room between watching and playing
+fn set_spectator(ctx: &ReducerContext, jr: JoinRoom, spectator: bool) {
+    if jr.spectator == spectator {
+        return;
+    }
+    let room_id = jr.room_id;
+    ctx.db
+        .join_room()
+        .joiner()
+        .update(JoinRoom { spectator, ..jr });
+    update_spectator_count(ctx, room_id);
+}
+
 #[spacetimedb::table(name = auto_delete_room_timer, scheduled(auto_delete_room_if_all_offline))]
 pub struct AutoDeleteRoomTimer {
     #[primary_key]
@@ -311,360 +1085,1362 @@ fn auto_delete_room_if_all_offline(ctx: &ReducerContext, _timer: AutoDeleteRoomT
     }
 }
 
-fn leave_team(ctx: &ReducerContext) {
+fn leave_team(ctx: &ReducerContext) -> Result<(), String> {
     let Some(jt) = ctx.db.join_team().joiner().find(ctx.sender) else {
         // player was not in a game
-        return;
+        return Ok(());
     };
 
+    // the last player of a team leaving in the middle of a game gives the win away
+    if let Some(game) = ctx.db.game().room_id().find(jt.room_id) {
+        let is_last_member = ctx.db.join_team().team_id().filter(jt.team_id).count() == 1;
+        if game.is_in_progress() && is_last_member {
+            forfeit_game(ctx, game, jt.team_id)?;
+        }
+    }
+
     delete_join_team(ctx, DeleteJoinTeamBy::Joiner(ctx.sender));
+    delete_team_vote(ctx, DeleteTeamVoteBy::Voter(ctx.sender));
+    delete_rematch_vote(ctx, DeleteRematchVoteBy::Voter(ctx.sender));
+    update_dropper(ctx, jt.room_id);
 
     // remove game if there are no player in all teams
     // TODO: Maybe in the future we could keep the game alive as long as there are players in the room.
     if ctx.db.join_team().room_id().filter(jt.room_id).count() == 0 {
         delete_game(ctx, DeleteGameBy::RoomId(jt.room_id));
     }
-}
 
-fn cell_belongs_to_team(cell: &Cell, team_id: u32) -> bool {
-    cell.as_ref().map_or(false, |c| c.team_id == team_id)
+    update_first_turn_clock(ctx, jt.room_id)
 }
 
-fn check_win(table: &GameTable, team_id: u32) -> Option<Vec<Coord>> {
-    let rows = table.len();
-    let cols = table[0].len();
+fn game_of_sender(ctx: &ReducerContext) -> Result<Game, String> {
+    let (_, game) = auth::team_member(ctx)?;
+    Ok(game)
+}
 
-    // Check horizontal
-    for row in 0..rows {
-        for col in 0..=(cols - STREAK_REQUIRED) {
-            let cols_to_check = col..col + STREAK_REQUIRED;
-            let cells_to_check = &table[row][cols_to_check.clone()];
-            if cells_to_check
-                .iter()
-                .all(|cell| cell_belongs_to_team(&cell, team_id))
-            {
-                return Some(
-                    cols_to_check
-                        .map(|col| Coord {
-                            x: row as u32,
-                            y: col as u32,
-                        })
-                        .collect(),
-                );
-            }
+/// Team that starts the next round according to the start policy of the room
+fn next_starting_team(ctx: &ReducerContext, game: &Game) -> Option<Team> {
+    let policy = ctx
+        .db
+        .room()
+        .id()
+        .find(game.room_id)
+        .map_or(StartPolicy::LoserStarts, |room| room.start_policy);
+    match (policy, &game.winner) {
+        (StartPolicy::LoserStarts, Some(winner)) => next_team(ctx, game, winner.team_id),
+        (StartPolicy::Alternate, _) | (StartPolicy::LoserStarts, None) => {
+            next_team(ctx, game, game.starting_team_id)
         }
+        (StartPolicy::Random, _) => ctx
+            .db
+            .team()
+            .game_id()
+            .filter(game.room_id)
+            .filter(|team| team_can_play(ctx, team))
+            .choose(&mut ctx.rng())
+            .or_else(|| next_team(ctx, game, game.starting_team_id)),
     }
+}
 
-    // Check vertical
-    for col in 0..cols {
-        for row in 0..=(rows - STREAK_REQUIRED) {
-            let rows_to_check = row..row + STREAK_REQUIRED;
-            let mut cells_to_check = rows_to_check.clone().map(|row| &table[row][col]);
-            if cells_to_check.all(|cell| cell_belongs_to_team(&cell, team_id)) {
-                return Some(
-                    rows_to_check
-                        .map(|row| Coord {
-                            x: row as u32,
-                            y: col as u32,
-                        })
-                        .collect(),
-                );
-            }
-        }
+fn restart_game(ctx: &ReducerContext, game: Game) -> Result<(), String> {
+    for team in ctx.db.team().game_id().filter(game.room_id) {
+        let had_player = ctx
+            .db
+            .join_team()
+            .team_id()
+            .filter(team.id)
+            .next()
+            .is_some();
+        ctx.db.team().id().update(Team {
+            time_left: total_time(&game.settings),
+            eliminated: false,
+            had_player,
+            ..team
+        });
     }
+    let starting_team = next_starting_team(ctx, &game).ok_or("Cannot find a team to start")?;
+    delete_rematch_vote(ctx, DeleteRematchVoteBy::GameId(game.room_id));
+    let game = ctx.db.game().room_id().update(Game::new(
+        game.room_id,
+        game.round + 1,
+        game.settings,
+        game.turn_order,
+        starting_team.id,
+    ));
+    let game_current_team = ctx
+        .db
+        .game_current_team()
+        .game_id()
+        .find(game.room_id)
+        .ok_or("Cannot find game")?;
+    start_turn(ctx, &game, game_current_team, starting_team)
+}
 
-    // Check diagonal (top-left to bottom-right)
-    for row in 0..=(rows - STREAK_REQUIRED) {
-        for col in 0..=(cols - STREAK_REQUIRED) {
-            let mut cells_to_check = (0..STREAK_REQUIRED).map(|i| &table[row + i][col + i]);
-            if cells_to_check.all(|cell| cell_belongs_to_team(&cell, team_id)) {
-                return Some(
-                    (0..STREAK_REQUIRED)
-                        .map(|i| Coord {
-                            x: (row + i) as u32,
-                            y: (col + i) as u32,
-                        })
-                        .collect(),
-                );
-            }
-        }
-    }
+/// A player that wants to play another round once the game has ended
+#[table(name = rematch_vote, public)]
+pub struct RematchVote {
+    #[primary_key]
+    voter: Identity,
+    #[index(btree)]
+    game_id: u32,
+    /// round that has ended
+    round: u32,
+}
 
-    // Check diagonal (bottom-left to top-right)
-    for row in (STREAK_REQUIRED - 1)..rows {
-        for col in 0..=(cols - STREAK_REQUIRED) {
-            let mut cells_to_check = (0..STREAK_REQUIRED).map(|i| &table[row - i][col + i]);
-            if cells_to_check.all(|cell| cell_belongs_to_team(&cell, team_id)) {
-                return Some(
-                    (0..STREAK_REQUIRED)
-                        .map(|i| Coord {
-                            x: (row - i) as u32,
-                            y: (col + i) as u32,
-                        })
-                        .collect(),
-                );
-            }
+enum DeleteRematchVoteBy {
+    Voter(Identity),
+    GameId(u32),
+}
+
+fn delete_rematch_vote(ctx: &ReducerContext, by: DeleteRematchVoteBy) {
+    match by {
+        DeleteRematchVoteBy::Voter(voter) => {
+            ctx.db.rematch_vote().voter().delete(voter);
+        }
+        DeleteRematchVoteBy::GameId(game_id) => {
+            ctx.db.rematch_vote().game_id().delete(game_id);
         }
     }
-
-    None
 }
 
-fn game_of_sender(ctx: &ReducerContext) -> Result<Game, String> {
-    let Some(jt) = ctx.db.join_team().joiner().find(ctx.sender) else {
-        return Err("Player not in a team".to_string());
-    };
-
-    let Some(game) = ctx.db.game().room_id().find(jt.room_id) else {
-        return Err("Game not found".to_string());
-    };
-
-    Ok(game)
+#[spacetimedb::table(name = rematch_timer, scheduled(rematch_timeout))]
+pub struct RematchTimer {
+    #[primary_key]
+    #[auto_inc]
+    scheduled_id: u64,
+    scheduled_at: spacetimedb::ScheduleAt,
+    game_id: u32,
+    round: u32,
 }
 
 #[reducer]
-pub fn restart_game_table_full(ctx: &ReducerContext) -> Result<(), String> {
+pub fn vote_rematch(ctx: &ReducerContext) -> Result<(), String> {
     let game = game_of_sender(ctx)?;
 
-    if !game.is_table_full() {
-        return Err("Cannot restart game if the table is not full".to_string());
+    if !game.is_over() {
+        return Err("Cannot vote for a rematch before the game has ended".to_string());
     }
 
-    ctx.db.game().room_id().update(Game::new(game.room_id));
-    let game_current_team = ctx
+    if ctx.db.rematch_vote().voter().find(ctx.sender).is_some() {
+        return Err("Cannot vote for a rematch twice".to_string());
+    }
+
+    let is_first_vote = ctx
         .db
-        .game_current_team()
+        .rematch_vote()
         .game_id()
-        .find(game.room_id)
-        .ok_or("Cannot find game")?;
-    game_switch_team(ctx, &game, game_current_team)?;
+        .filter(game.room_id)
+        .next()
+        .is_none();
+    ctx.db.rematch_vote().try_insert(RematchVote {
+        voter: ctx.sender,
+        game_id: game.room_id,
+        round: game.round,
+    })?;
+    if is_first_vote {
+        ctx.db.rematch_timer().try_insert(RematchTimer {
+            scheduled_id: 0,
+            scheduled_at: ScheduleAt::Time(ctx.timestamp + REMATCH_TIMEOUT),
+            game_id: game.room_id,
+            round: game.round,
+        })?;
+    }
+
+    let votes = ctx.db.rematch_vote().game_id().filter(game.room_id).count() as u32;
+    let players = ctx.db.join_team().room_id().filter(game.room_id).count() as u32;
+    if votes * 100 >= game.settings.rematch_quorum * players {
+        restart_game(ctx, game)?;
+    }
 
     Ok(())
 }
 
 #[reducer]
-pub fn restart_game_has_winner(ctx: &ReducerContext) -> Result<(), String> {
-    let game = game_of_sender(ctx)?;
+fn rematch_timeout(ctx: &ReducerContext, timer: RematchTimer) -> Result<(), String> {
+    if ctx.sender != ctx.identity() {
+        return Err("Reducer `rematch_timeout` may only be invoked by the scheduler".to_string());
+    }
 
-    if game.winner.is_none() {
-        return Err("Cannot restart game if there is no winner".to_string());
+    let Some(game) = ctx.db.game().room_id().find(timer.game_id) else {
+        return Ok(());
+    };
+    if game.round != timer.round || !game.is_over() {
+        // the rematch has already started
+        return Ok(());
     }
 
-    ctx.db.game().room_id().update(Game::new(game.room_id));
-    let game_current_team = ctx
-        .db
-        .game_current_team()
-        .game_id()
-        .find(game.room_id)
-        .ok_or("Cannot find game")?;
-    game_switch_team(ctx, &game, game_current_team)?;
+    restart_game(ctx, game)
+}
 
-    Ok(())
+enum GameEnd {
+    /// `game.winner` has connected a streak, or nobody has and the table is full
+    Finished,
+    /// the team gave up, `game.winner` is the team that was left
+    Forfeit { team_id: u32 },
+    /// no other team was left to give the win to
+    Abandoned,
 }
 
-fn post_game_end(ctx: &ReducerContext, game_id: u32, winner_team_id: u32) -> Result<(), String> {
-    for jt in ctx.db.join_team().room_id().filter(game_id) {
-        let won = jt.team_id == winner_team_id;
+fn post_game_end(ctx: &ReducerContext, game: &Game, end: GameEnd) -> Result<(), String> {
+    let archive = archive_game(ctx, game)?;
+
+    // stop the clock
+    if let Some(game_current_team) = ctx.db.game_current_team().game_id().find(game.room_id) {
+        ctx.db
+            .game_current_team()
+            .game_id()
+            .update(GameCurrentTeam {
+                deadline: None,
+                ..game_current_team
+            });
+    }
+
+    let teams: Vec<Team> = ctx.db.team().game_id().filter(game.room_id).collect();
+    let eliminated: Vec<u32> = teams
+        .iter()
+        .filter(|team| team.eliminated)
+        .map(|team| team.id)
+        .collect();
+
+    for jt in ctx.db.join_team().room_id().filter(game.room_id) {
+        let outcome = match (&end, &game.winner) {
+            (GameEnd::Abandoned, _) => GameOutcome::Abandoned,
+            (_, Some(winner)) if winner.team_id == jt.team_id => GameOutcome::Win,
+            (GameEnd::Forfeit { team_id }, _) if *team_id == jt.team_id => GameOutcome::Forfeit,
+            _ if eliminated.contains(&jt.team_id) => GameOutcome::Forfeit,
+            (_, Some(_)) => GameOutcome::Loss,
+            (_, None) => GameOutcome::Draw,
+        };
+
+        // a win against a bot or a team nobody played for is not counted
+        let counted = game.settings.bot.is_none()
+            && teams
+                .iter()
+                .all(|team| team.id == jt.team_id || team.had_player);
 
         // create game history
-        ctx.db.game_history().try_insert(GameHistory {
+        let history = ctx.db.game_history().try_insert(GameHistory {
             id: 0,
             player: jt.joiner,
+            archive_id: archive.id,
             timestamp: ctx.timestamp,
-            won,
+            outcome,
+            went_first: jt.team_id == game.starting_team_id,
+            day: leaderboard::day_of(ctx.timestamp),
+            counted,
         })?;
 
-        // update stats
-        let one_month_dur = Duration::from_secs(60 * 60 * 24 * 30);
-        let mut one_month_total = 0;
-        let mut one_month_wins = 0;
-        for history in ctx.db.game_history().player().filter(jt.joiner) {
-            let elapsed = ctx
-                .timestamp
-                .duration_since(history.timestamp)
-                .ok_or("Timestamp is in the future")?;
-            if elapsed <= one_month_dur {
-                one_month_total += 1;
-                if history.won {
-                    one_month_wins += 1;
-                }
-            }
+        // coins are only earned against teams someone played for, never against a bot
+        if history.counted {
+            leaderboard::record_game(ctx, jt.joiner, &history.outcome)?;
+            wallet::reward_game(ctx, jt.joiner, archive.id, history.outcome)?;
         }
-        if let Some(stat) = ctx.db.stats_one_month().player().find(jt.joiner) {
-            ctx.db.stats_one_month().player().update(StatsOneMonth {
-                wins: one_month_wins,
-                total: one_month_total,
-                ..stat
-            });
-        } else {
-            ctx.db.stats_one_month().try_insert(StatsOneMonth {
-                player: jt.joiner,
-                wins: one_month_wins,
-                total: one_month_total,
-            })?;
+    }
+
+    if game.settings.ranked && !matches!(end, GameEnd::Abandoned) {
+        update_ratings(ctx, game, &end)?;
+    }
+
+    Ok(())
+}
+
+/// The team is still in the game and has someone to drop its pieces
+fn team_can_play(ctx: &ReducerContext, team: &Team) -> bool {
+    !team.eliminated
+        && (team.bot.is_some()
+            || ctx
+                .db
+                .join_team()
+                .team_id()
+                .filter(team.id)
+                .next()
+                .is_some())
+}
+
+/// Takes `team_id` out of the game when it gives up. The game goes on while at least two teams can
+/// still play, otherwise it ends in favor of the team that is left.
+fn forfeit_game(ctx: &ReducerContext, mut game: Game, team_id: u32) -> Result<(), String> {
+    let mut remaining_teams = ctx
+        .db
+        .team()
+        .game_id()
+        .filter(game.room_id)
+        .filter(|team| team.id != team_id && team_can_play(ctx, team));
+
+    let Some(remaining_team) = remaining_teams.next() else {
+        // nobody is left to win, the game is removed so that it cannot be given up again
+        post_game_end(ctx, &game, GameEnd::Abandoned)?;
+        delete_join_team(ctx, DeleteJoinTeamBy::RoomId(game.room_id));
+        delete_game(ctx, DeleteGameBy::RoomId(game.room_id));
+        return Ok(());
+    };
+
+    if remaining_teams.next().is_some() {
+        let team = ctx.db.team().id().find(team_id).ok_or("Cannot find team")?;
+        ctx.db.team().id().update(Team {
+            eliminated: true,
+            ..team
+        });
+        let game_current_team = ctx
+            .db
+            .game_current_team()
+            .game_id()
+            .find(game.room_id)
+            .ok_or("Cannot find game")?;
+        if game_current_team.team_id == team_id {
+            game_switch_team(ctx, &game, game_current_team)?;
         }
+        return Ok(());
     }
 
+    game.winner = Some(Winner {
+        team_id: remaining_team.id,
+        coordinates: vec![],
+    });
+    post_game_end(ctx, &game, GameEnd::Forfeit { team_id })?;
+    ctx.db.game().room_id().update(game);
+
     Ok(())
 }
 
+#[reducer]
+pub fn resign(ctx: &ReducerContext) -> Result<(), String> {
+    let (jt, game) = auth::team_member(ctx)?;
+
+    if !game.is_in_progress() {
+        return Err("Cannot resign if the game is not in progress".to_string());
+    }
+
+    forfeit_game(ctx, game, jt.team_id)
+}
+
+/// How long every player of a team can stay disconnected before the team forfeits
+const FORFEIT_GRACE_PERIOD: Duration = Duration::from_secs(60 * 2);
+
+#[spacetimedb::table(name = forfeit_timer, scheduled(forfeit_if_team_offline))]
+pub struct ForfeitTimer {
+    #[primary_key]
+    #[auto_inc]
+    scheduled_id: u64,
+    scheduled_at: spacetimedb::ScheduleAt,
+    game_id: u32,
+    round: u32,
+    team_id: u32,
+}
+
+fn is_online(ctx: &ReducerContext, identity: Identity) -> bool {
+    ctx.db
+        .player()
+        .identity()
+        .find(identity)
+        .is_some_and(|p| p.online)
+}
+
+#[reducer]
+fn forfeit_if_team_offline(ctx: &ReducerContext, timer: ForfeitTimer) -> Result<(), String> {
+    if ctx.sender != ctx.identity() {
+        return Err(
+            "Reducer `forfeit_if_team_offline` may only be invoked by the scheduler".to_string(),
+        );
+    }
+
+    let Some(game) = ctx.db.game().room_id().find(timer.game_id) else {
+        return Ok(());
+    };
+    if game.round != timer.round || !clock_running(ctx, &game) {
+        return Ok(());
+    }
+
+    let (team, others): (Vec<JoinTeam>, Vec<JoinTeam>) = ctx
+        .db
+        .join_team()
+        .room_id()
+        .filter(game.room_id)
+        .partition(|jt| jt.team_id == timer.team_id);
+    if team.iter().any(|jt| is_online(ctx, jt.joiner)) {
+        // someone of the team came back in time
+        return Ok(());
+    }
+    if !others.iter().any(|jt| is_online(ctx, jt.joiner)) {
+        // nobody is left to claim the win, the room will be cleaned up when everyone is offline
+        return Ok(());
+    }
+
+    forfeit_game(ctx, game, timer.team_id)
+}
+
+fn total_time(settings: &GameSettings) -> Option<TimeDuration> {
+    settings
+        .total_seconds
+        .map(|secs| TimeDuration::from_duration(Duration::from_secs(secs.into())))
+}
+
 fn game_switch_team(
     ctx: &ReducerContext,
     game: &Game,
     game_current_team: GameCurrentTeam,
 ) -> Result<(), String> {
-    let another_team = ctx
-        .db
-        .team()
-        .game_id()
-        .filter(game.room_id)
-        .filter(|team| team.id != game_current_team.team_id)
-        .next()
-        .ok_or("Cannot find another team")?;
+    // the time of a turn is taken from the team's clock when the turn ends
+    if game_current_team.deadline.is_some() {
+        if let Some(team) = ctx.db.team().id().find(game_current_team.team_id) {
+            if let Some(time_left) = team.time_left {
+                let elapsed = ctx
+                    .timestamp
+                    .duration_since(game_current_team.turn_started_at)
+                    .unwrap_or_default();
+                let time_left = time_left.to_duration_saturating().saturating_sub(elapsed);
+                ctx.db.team().id().update(Team {
+                    time_left: Some(TimeDuration::from_duration(time_left)),
+                    ..team
+                });
+            }
+        }
+    }
+
+    let another_team =
+        next_team(ctx, game, game_current_team.team_id).ok_or("Cannot find another team")?;
+    start_turn(ctx, game, game_current_team, another_team)
+}
+
+/// Gives the turn to `team`, starting its clock and letting the bot play if it is its team
+fn start_turn(
+    ctx: &ReducerContext,
+    game: &Game,
+    game_current_team: GameCurrentTeam,
+    team: Team,
+) -> Result<(), String> {
+    // on a full pop out board, a team without a piece on the bottom row has no move left
+    if game.settings.variant == Variant::PopOut && !game.is_over() {
+        let board = game.board.to_board(&game.settings)?;
+        if board.is_full() && board.pop_moves(team.id).next().is_none() {
+            let mut game = ctx
+                .db
+                .game()
+                .room_id()
+                .find(game.room_id)
+                .ok_or("Cannot find game")?;
+            game.drawn = true;
+            post_game_end(ctx, &game, GameEnd::Finished)?;
+            ctx.db.game().room_id().update(game);
+            return Ok(());
+        }
+    }
+
+    let deadline = if clock_running(ctx, game) {
+        turn_deadline(ctx, game, &team)
+    } else {
+        None
+    };
     ctx.db
         .game_current_team()
         .game_id()
         .update(GameCurrentTeam {
-            team_id: another_team.id,
+            team_id: team.id,
+            turn_started_at: ctx.timestamp,
+            deadline,
+            dropper: next_dropper(ctx, game, &team),
+            vote_deadline: None,
             ..game_current_team
         });
+    // votes and takebacks are only for the turn they were asked in
+    delete_team_vote(ctx, DeleteTeamVoteBy::GameId(game.room_id));
+    ctx.db.takeback_request().game_id().delete(game.room_id);
+    if let Some(deadline) = deadline {
+        ctx.db.turn_timer().try_insert(TurnTimer {
+            scheduled_id: 0,
+            scheduled_at: ScheduleAt::Time(deadline),
+            game_id: game.room_id,
+            deadline,
+        })?;
+    }
+
+    play_bot_turn(ctx, game.room_id)
+}
+
+/// Team playing after `team_id` in the turn order. Eliminated teams are always skipped, and so are
+/// teams without players as long as another team can play.
+fn next_team(ctx: &ReducerContext, game: &Game, team_id: u32) -> Option<Team> {
+    let start = game
+        .turn_order
+        .iter()
+        .position(|&id| id == team_id)
+        .map_or(0, |i| i + 1);
+    let candidates: Vec<Team> = (0..game.turn_order.len())
+        .map(|i| game.turn_order[(start + i) % game.turn_order.len()])
+        .filter_map(|id| ctx.db.team().id().find(id))
+        .filter(|team| !team.eliminated)
+        .collect();
+    let index = candidates
+        .iter()
+        .position(|team| team.id != team_id && team_can_play(ctx, team))
+        .unwrap_or(0);
+    candidates.into_iter().nth(index)
+}
+
+/// Member of `team` that drops its next piece when the game uses `DropMode::Rotation`: the first
+/// online member that joined after the last dropper, wrapping around to the earliest one
+fn next_dropper(ctx: &ReducerContext, game: &Game, team: &Team) -> Option<Identity> {
+    if game.settings.drop_mode != DropMode::Rotation {
+        return None;
+    }
+
+    let mut members: Vec<JoinTeam> = ctx.db.join_team().team_id().filter(team.id).collect();
+    members.sort_by_key(|jt| jt.joined_at);
+    let start = team
+        .last_dropper
+        .and_then(|last| members.iter().position(|jt| jt.joiner == last))
+        .map_or(0, |i| i + 1);
+    (0..members.len())
+        .map(|i| members[(start + i) % members.len()].joiner)
+        .find(|&joiner| is_online(ctx, joiner))
+}
+
+/// Member of the team just before `dropper` in the rotation, so that `dropper` is the next one
+fn dropper_before(ctx: &ReducerContext, team_id: u32, dropper: Identity) -> Option<Identity> {
+    let mut members: Vec<JoinTeam> = ctx.db.join_team().team_id().filter(team_id).collect();
+    members.sort_by_key(|jt| jt.joined_at);
+    let i = members.iter().position(|jt| jt.joiner == dropper)?;
+    Some(members[(i + members.len() - 1) % members.len()].joiner)
+}
+
+/// Moves the rotation of the current team past its designated dropper, also when the turn ran out
+/// and the module played or skipped it for them
+fn end_dropper_turn(ctx: &ReducerContext, game_current_team: &GameCurrentTeam) {
+    let Some(last_dropper) = game_current_team.dropper else {
+        return;
+    };
+    if let Some(team) = ctx.db.team().id().find(game_current_team.team_id) {
+        ctx.db.team().id().update(Team {
+            last_dropper: Some(last_dropper),
+            ..team
+        });
+    }
+}
+
+/// Recomputes the designated dropper of the current team after its members or their online status
+/// have changed
+fn update_dropper(ctx: &ReducerContext, game_id: u32) {
+    let Some(game) = ctx.db.game().room_id().find(game_id) else {
+        return;
+    };
+    let Some(game_current_team) = ctx.db.game_current_team().game_id().find(game_id) else {
+        return;
+    };
+    let Some(team) = ctx.db.team().id().find(game_current_team.team_id) else {
+        return;
+    };
+    let dropper = next_dropper(ctx, &game, &team);
+    if dropper != game_current_team.dropper {
+        ctx.db
+            .game_current_team()
+            .game_id()
+            .update(GameCurrentTeam {
+                dropper,
+                ..game_current_team
+            });
+    }
+}
+
+/// Drops a piece right away when the team whose turn it is is played by the module
+fn play_bot_turn(ctx: &ReducerContext, game_id: u32) -> Result<(), String> {
+    let game_current_team = ctx
+        .db
+        .game_current_team()
+        .game_id()
+        .find(game_id)
+        .ok_or("Cannot find game")?;
+    let team = ctx
+        .db
+        .team()
+        .id()
+        .find(game_current_team.team_id)
+        .ok_or("Cannot find team")?;
+    let Some(difficulty) = team.bot else {
+        return Ok(());
+    };
+    let game = ctx
+        .db
+        .game()
+        .room_id()
+        .find(game_id)
+        .ok_or("Cannot find game")?;
+    if game.is_over() {
+        return Ok(());
+    }
+
+    // the search only looks at the team playing right after the bot
+    let opponent = next_team(ctx, &game, team.id).ok_or("Cannot find another team")?;
+    let board = game.board.to_board(&game.settings)?;
+    let (kind, column) =
+        match bot::choose_column(&board, team.id, opponent.id, difficulty, &mut ctx.rng()) {
+            Some(column) => (MoveKind::Drop, column),
+            // only a full board of the pop out variant is left to play on
+            None => (
+                MoveKind::Pop,
+                random_pop_column(ctx, &board, team.id).ok_or("Cannot find a move to play")?,
+            ),
+        };
+
+    play_piece(
+        ctx,
+        game,
+        game_current_team,
+        team.id,
+        ctx.identity(),
+        kind,
+        column,
+    )
+}
+
+/// The clocks run once the first piece has been dropped, and before that as soon as every team has
+/// someone to play it, so that the starting team cannot stall the game
+fn clock_running(ctx: &ReducerContext, game: &Game) -> bool {
+    game.is_in_progress()
+        || (!game.is_over()
+            && game
+                .turn_order
+                .iter()
+                .filter_map(|&id| ctx.db.team().id().find(id))
+                .all(|team| team_can_play(ctx, &team)))
+}
+
+/// Before the first piece is dropped, starts the clock of the first turn when the last team gets a
+/// player, and stops it when a team is left without one
+fn update_first_turn_clock(ctx: &ReducerContext, game_id: u32) -> Result<(), String> {
+    let Some(game) = ctx.db.game().room_id().find(game_id) else {
+        return Ok(());
+    };
+    if game.latest_move.is_some() || game.is_over() {
+        return Ok(());
+    }
+    // without a time limit there is no deadline telling whether the turn has started
+    if game.settings.turn_seconds.is_none() && game.settings.total_seconds.is_none() {
+        return Ok(());
+    }
+    let Some(game_current_team) = ctx.db.game_current_team().game_id().find(game_id) else {
+        return Ok(());
+    };
+    if clock_running(ctx, &game) {
+        if game_current_team.deadline.is_none() {
+            let team = ctx
+                .db
+                .team()
+                .id()
+                .find(game_current_team.team_id)
+                .ok_or("Cannot find current team")?;
+            start_turn(ctx, &game, game_current_team, team)?;
+        }
+    } else if game_current_team.deadline.is_some() {
+        ctx.db
+            .game_current_team()
+            .game_id()
+            .update(GameCurrentTeam {
+                deadline: None,
+                ..game_current_team
+            });
+    }
     Ok(())
 }
 
-#[reducer]
-pub fn drop_piece(ctx: &ReducerContext, column: u32) -> Result<(), String> {
-    // check if the player is in a team
-    let Some(jt) = ctx.db.join_team().joiner().find(ctx.sender) else {
-        return Err("Cannot drop piece if not in a team".to_string());
+/// Column whose bottom piece the team can pop out, picked at random
+fn random_pop_column(ctx: &ReducerContext, board: &Board, team_id: u32) -> Option<u32> {
+    board
+        .pop_moves(team_id)
+        .choose(&mut ctx.rng())
+        .map(|col| col as u32)
+}
+
+/// End of the turn starting now for `team`, the shortest of the turn time and the team's clock
+fn turn_deadline(ctx: &ReducerContext, game: &Game, team: &Team) -> Option<Timestamp> {
+    let turn_time = game
+        .settings
+        .turn_seconds
+        .map(|secs| Duration::from_secs(secs.into()));
+    let time_left = team.time_left.map(|t| t.to_duration_saturating());
+    let budget = match (turn_time, time_left) {
+        (Some(turn_time), Some(time_left)) => Some(turn_time.min(time_left)),
+        (turn_time, time_left) => turn_time.or(time_left),
     };
+    budget.map(|budget| ctx.timestamp + budget)
+}
 
-    let Some(mut game) = ctx.db.game().room_id().find(jt.room_id) else {
-        return Err("Cannot drop piece if game does not exist".to_string());
+#[spacetimedb::table(name = turn_timer, scheduled(turn_timeout))]
+pub struct TurnTimer {
+    #[primary_key]
+    #[auto_inc]
+    scheduled_id: u64,
+    scheduled_at: spacetimedb::ScheduleAt,
+    game_id: u32,
+    /// deadline of the turn this timer was scheduled for
+    deadline: Timestamp,
+}
+
+#[reducer]
+fn turn_timeout(ctx: &ReducerContext, timer: TurnTimer) -> Result<(), String> {
+    if ctx.sender != ctx.identity() {
+        return Err("Reducer `turn_timeout` may only be invoked by the scheduler".to_string());
+    }
+
+    let Some(game_current_team) = ctx.db.game_current_team().game_id().find(timer.game_id) else {
+        return Ok(());
+    };
+    if game_current_team.deadline != Some(timer.deadline) {
+        // the team has played in time
+        return Ok(());
+    }
+    let Some(game) = ctx.db.game().room_id().find(timer.game_id) else {
+        return Ok(());
     };
+    if !clock_running(ctx, &game) {
+        return Ok(());
+    }
 
-    let col_usize = column as usize;
-    if col_usize >= game.table[0].len() {
-        return Err("Column index out of bounds".to_string());
+    let elapsed = ctx
+        .timestamp
+        .duration_since(game_current_team.turn_started_at)
+        .unwrap_or_default();
+    let out_of_total_time = ctx
+        .db
+        .team()
+        .id()
+        .find(game_current_team.team_id)
+        .and_then(|team| team.time_left)
+        .is_some_and(|time_left| time_left.to_duration_saturating() <= elapsed);
+    if out_of_total_time {
+        return forfeit_game(ctx, game, game_current_team.team_id);
     }
 
-    if game.winner.is_some() {
-        return Err("Cannot drop piece if game is already won".to_string());
+    // votes that have not been counted yet are played instead of the timeout action
+    let has_votes = ctx
+        .db
+        .team_vote()
+        .team_id()
+        .filter(game_current_team.team_id)
+        .next()
+        .is_some();
+    if game.settings.drop_mode == DropMode::Vote && has_votes {
+        return play_voted_column(ctx, game, game_current_team);
     }
 
+    match game.settings.timeout_action {
+        TimeoutAction::SkipTurn => {
+            end_dropper_turn(ctx, &game_current_team);
+            game_switch_team(ctx, &game, game_current_team)
+        }
+        TimeoutAction::RandomMove => {
+            let team_id = game_current_team.team_id;
+            let board = game.board.to_board(&game.settings)?;
+            let drop_column = board.legal_moves().choose(&mut ctx.rng());
+            let (kind, column) = match drop_column {
+                Some(column) => (MoveKind::Drop, column as u32),
+                None => (
+                    MoveKind::Pop,
+                    random_pop_column(ctx, &board, team_id).ok_or("Cannot find a move to play")?,
+                ),
+            };
+            play_piece(
+                ctx,
+                game,
+                game_current_team,
+                team_id,
+                ctx.identity(),
+                kind,
+                column,
+            )
+        }
+        TimeoutAction::Forfeit => forfeit_game(ctx, game, game_current_team.team_id),
+    }
+}
+
+#[reducer]
+pub fn drop_piece(ctx: &ReducerContext, column: u32) -> Result<(), String> {
+    play_move_of_sender(ctx, MoveKind::Drop, column)
+}
+
+/// Removes a piece of the sender's team from the bottom of the column, in `Variant::PopOut` games
+#[reducer]
+pub fn pop_piece(ctx: &ReducerContext, column: u32) -> Result<(), String> {
+    play_move_of_sender(ctx, MoveKind::Pop, column)
+}
+
+fn play_move_of_sender(ctx: &ReducerContext, kind: MoveKind, column: u32) -> Result<(), String> {
+    let (jt, game) = auth::team_member(ctx)?;
+
     let Some(game_current_team) = ctx.db.game_current_team().game_id().find(game.room_id) else {
         log::error!("Game current team not found for game {}. It appears that no current team was created when the game was created.", game.room_id);
         return Err("Cannot drop piece if there is no current team in game".to_string());
     };
 
-    if jt.team_id != game_current_team.team_id {
-        return Err("Cannot drop piece if it's not your team's turn".to_string());
+    auth::ensure_turn(&jt, &game_current_team)?;
+
+    match game.settings.drop_mode {
+        DropMode::Anyone => {}
+        DropMode::Rotation => {
+            if game_current_team.dropper != Some(ctx.sender) {
+                return Err("Cannot drop piece if it's another member's turn".to_string());
+            }
+        }
+        DropMode::Vote => {
+            return Err("Cannot drop piece when the team votes on its moves".to_string());
+        }
+    }
+
+    play_piece(
+        ctx,
+        game,
+        game_current_team,
+        jt.team_id,
+        ctx.sender,
+        kind,
+        column,
+    )
+}
+
+#[reducer]
+pub fn vote_column(ctx: &ReducerContext, column: u32) -> Result<(), String> {
+    let (jt, game) = auth::team_member(ctx)?;
+
+    if game.settings.drop_mode != DropMode::Vote {
+        return Err("Cannot vote when the game is not played by vote".to_string());
     }
 
-    // check if the column is full
-    if game.table[0][col_usize].is_some() {
-        return Err("Cannot drop piece in a full column".to_string());
+    let Some(mut game_current_team) = ctx.db.game_current_team().game_id().find(game.room_id)
+    else {
+        return Err("Cannot vote if there is no current team in game".to_string());
+    };
+
+    auth::ensure_turn(&jt, &game_current_team)?;
+
+    if game.is_over() {
+        return Err("Cannot vote when the game is over".to_string());
     }
 
-    for i in (0..game.table.len()).rev() {
-        // find the first topmost empty cell in the column
-        if game.table[i][col_usize].is_none() {
-            game.table[i][col_usize] = Some(DroppedPiece {
-                team_id: jt.team_id,
-                dropper: ctx.sender,
-            });
-            game.latest_move = Some(Coord {
-                x: i as u32,
-                y: column,
-            });
+    let is_free = game
+        .board
+        .heights
+        .get(column as usize)
+        .is_some_and(|&height| (height as u32) < game.settings.rows);
+    if !is_free {
+        return Err("Cannot vote for a column that is full or out of bounds".to_string());
+    }
 
-            if let Some(coords) = check_win(&game.table, jt.team_id) {
-                game.winner = Some(Winner {
-                    team_id: jt.team_id,
-                    coordinates: coords,
+    delete_team_vote(ctx, DeleteTeamVoteBy::Voter(ctx.sender));
+    ctx.db.team_vote().try_insert(TeamVote {
+        voter: ctx.sender,
+        game_id: game.room_id,
+        team_id: jt.team_id,
+        column,
+        voted_at: ctx.timestamp,
+    })?;
+
+    // the first vote of the turn starts the countdown
+    let vote_deadline = match game_current_team.vote_deadline {
+        Some(vote_deadline) => vote_deadline,
+        None => {
+            let vote_deadline = ctx.timestamp + VOTE_DURATION;
+            game_current_team = ctx
+                .db
+                .game_current_team()
+                .game_id()
+                .update(GameCurrentTeam {
+                    vote_deadline: Some(vote_deadline),
+                    ..game_current_team
                 });
-                post_game_end(ctx, game.room_id, jt.team_id)?;
-            } else {
-                game_switch_team(ctx, &game, game_current_team)?;
-            }
+            ctx.db.vote_timer().try_insert(VoteTimer {
+                scheduled_id: 0,
+                scheduled_at: ScheduleAt::Time(vote_deadline),
+                game_id: game.room_id,
+                vote_deadline,
+            })?;
+            vote_deadline
+        }
+    };
+
+    let everyone_voted = ctx
+        .db
+        .join_team()
+        .team_id()
+        .filter(jt.team_id)
+        .filter(|member| is_online(ctx, member.joiner))
+        .all(|member| ctx.db.team_vote().voter().find(member.joiner).is_some());
+    if everyone_voted || ctx.timestamp >= vote_deadline {
+        play_voted_column(ctx, game, game_current_team)?;
+    }
+
+    Ok(())
+}
+
+#[spacetimedb::table(name = vote_timer, scheduled(vote_timeout))]
+pub struct VoteTimer {
+    #[primary_key]
+    #[auto_inc]
+    scheduled_id: u64,
+    scheduled_at: spacetimedb::ScheduleAt,
+    game_id: u32,
+    /// vote deadline of the turn this timer was scheduled for
+    vote_deadline: Timestamp,
+}
+
+#[reducer]
+fn vote_timeout(ctx: &ReducerContext, timer: VoteTimer) -> Result<(), String> {
+    if ctx.sender != ctx.identity() {
+        return Err("Reducer `vote_timeout` may only be invoked by the scheduler".to_string());
+    }
+
+    let Some(game_current_team) = ctx.db.game_current_team().game_id().find(timer.game_id) else {
+        return Ok(());
+    };
+    if game_current_team.vote_deadline != Some(timer.vote_deadline) {
+        // the votes have already been counted
+        return Ok(());
+    }
+    let Some(game) = ctx.db.game().room_id().find(timer.game_id) else {
+        return Ok(());
+    };
+    if game.is_over() {
+        return Ok(());
+    }
 
-            break;
+    play_voted_column(ctx, game, game_current_team)
+}
+
+/// Plays the column with the most votes of the current team, ties are broken at random. The piece
+/// is attributed to the earliest voter of that column.
+fn play_voted_column(
+    ctx: &ReducerContext,
+    game: Game,
+    game_current_team: GameCurrentTeam,
+) -> Result<(), String> {
+    let votes: Vec<TeamVote> = ctx
+        .db
+        .team_vote()
+        .team_id()
+        .filter(game_current_team.team_id)
+        .collect();
+
+    let mut counts: Vec<(u32, usize)> = vec![];
+    for vote in &votes {
+        match counts.iter_mut().find(|(column, _)| *column == vote.column) {
+            Some((_, count)) => *count += 1,
+            None => counts.push((vote.column, 1)),
         }
     }
+    let Some(most_votes) = counts.iter().map(|&(_, count)| count).max() else {
+        // nobody has voted, the turn timer decides what happens
+        return Ok(());
+    };
+    let column = counts
+        .into_iter()
+        .filter(|&(_, count)| count == most_votes)
+        .map(|(column, _)| column)
+        .choose(&mut ctx.rng())
+        .ok_or("Cannot find the most voted column")?;
+    let dropper = votes
+        .iter()
+        .filter(|vote| vote.column == column)
+        .min_by_key(|vote| vote.voted_at)
+        .map(|vote| vote.voter)
+        .ok_or("Cannot find a voter of the most voted column")?;
+
+    delete_team_vote(ctx, DeleteTeamVoteBy::GameId(game.room_id));
+    let team_id = game_current_team.team_id;
+    play_piece(
+        ctx,
+        game,
+        game_current_team,
+        team_id,
+        dropper,
+        MoveKind::Drop,
+        column,
+    )
+}
 
-    ctx.db.game().room_id().update(game);
+/// A team asking to take back the piece it has just dropped, waiting for the team whose turn it now
+/// is to accept
+#[table(name = takeback_request, public)]
+pub struct TakebackRequest {
+    #[primary_key]
+    game_id: u32,
+    round: u32,
+    /// ply of the move to take back
+    ply: u32,
+    team_id: u32,
+    requester: Identity,
+}
+
+#[reducer]
+pub fn request_takeback(ctx: &ReducerContext) -> Result<(), String> {
+    let (jt, game) = auth::team_member(ctx)?;
+
+    if game.settings.ranked {
+        return Err("Cannot take back a move in a ranked game".to_string());
+    }
+    // the bot replies right away, so the latest move is never the one of the players
+    if game.settings.bot.is_some() {
+        return Err("Cannot take back a move in a game against a bot".to_string());
+    }
+
+    if !game.is_in_progress() {
+        return Err("Cannot take back a move if the game is not in progress".to_string());
+    }
+
+    let Some(latest) = round_moves(ctx, game.room_id, game.round).pop() else {
+        return Err("Cannot take back a move before the first one".to_string());
+    };
+    if latest.team_id != jt.team_id {
+        return Err("Cannot take back a move of another team".to_string());
+    }
+
+    if ctx
+        .db
+        .takeback_request()
+        .game_id()
+        .find(game.room_id)
+        .is_some()
+    {
+        return Err("Cannot request a takeback twice".to_string());
+    }
+
+    ctx.db.takeback_request().try_insert(TakebackRequest {
+        game_id: game.room_id,
+        round: game.round,
+        ply: latest.ply,
+        team_id: jt.team_id,
+        requester: ctx.sender,
+    })?;
 
     Ok(())
 }
 
-fn validate_can_join_or_create(ctx: &ReducerContext) -> Result<JoinRoom, String> {
-    // check if the player is in a room
-    let Some(jr) = ctx.db.join_room().joiner().find(ctx.sender) else {
-        return Err("Cannot join the game when not in a room".to_string());
+/// Checks that the sender can answer the takeback request of their game, which is for the team
+/// whose turn it is
+fn takeback_to_answer(ctx: &ReducerContext) -> Result<(Game, TakebackRequest), String> {
+    let (jt, game) = auth::team_member(ctx)?;
+
+    let Some(request) = ctx.db.takeback_request().game_id().find(game.room_id) else {
+        return Err("There is no takeback to answer".to_string());
     };
 
+    let game_current_team = ctx
+        .db
+        .game_current_team()
+        .game_id()
+        .find(game.room_id)
+        .ok_or("Cannot find game")?;
+    auth::ensure_turn(&jt, &game_current_team)?;
+
+    Ok((game, request))
+}
+
+#[reducer]
+pub fn accept_takeback(ctx: &ReducerContext) -> Result<(), String> {
+    let (mut game, request) = takeback_to_answer(ctx)?;
+
+    let mut moves = round_moves(ctx, game.room_id, game.round);
+    let Some(latest) = moves.pop() else {
+        return Err("Cannot find the move to take back".to_string());
+    };
+    if game.round != request.round || latest.ply != request.ply || game.is_over() {
+        // the request has outlived its move
+        ctx.db.takeback_request().game_id().delete(game.room_id);
+        return Err("Cannot take back a move that is not the latest one".to_string());
+    }
+
+    let mut board = game.board.to_board(&game.settings)?;
+    let undone = match latest.kind {
+        MoveKind::Drop => board.undo(latest.column as usize) == Some(latest.team_id),
+        MoveKind::Pop => board.unpop(latest.column as usize, latest.team_id).is_ok(),
+    };
+    if !undone {
+        return Err("The board does not match the moves of the round".to_string());
+    }
+    ctx.db.game_move().id().delete(latest.id);
+    game.board = GameBoard::from(&board);
+    game.positions.pop();
+    game.latest_move = moves.last().map(|m| Coord {
+        x: m.row,
+        y: m.column,
+    });
+    let game = ctx.db.game().room_id().update(game);
+
+    let mut team = ctx
+        .db
+        .team()
+        .id()
+        .find(latest.team_id)
+        .ok_or("Cannot find team")?;
+    if game.settings.drop_mode == DropMode::Rotation {
+        // the member who made the move plays it again
+        if let Some(last_dropper) = dropper_before(ctx, team.id, latest.dropper) {
+            team = ctx.db.team().id().update(Team {
+                last_dropper: Some(last_dropper),
+                ..team
+            });
+        }
+    }
+    let game_current_team = ctx
+        .db
+        .game_current_team()
+        .game_id()
+        .find(game.room_id)
+        .ok_or("Cannot find game")?;
+    start_turn(ctx, &game, game_current_team, team)
+}
+
+#[reducer]
+pub fn decline_takeback(ctx: &ReducerContext) -> Result<(), String> {
+    let (game, _) = takeback_to_answer(ctx)?;
+    ctx.db.takeback_request().game_id().delete(game.room_id);
+    Ok(())
+}
+
+/// Drops or pops a piece for the team whose turn it is, ending the game or passing the turn
+fn play_piece(
+    ctx: &ReducerContext,
+    mut game: Game,
+    game_current_team: GameCurrentTeam,
+    team_id: u32,
+    dropper: Identity,
+    kind: MoveKind,
+    column: u32,
+) -> Result<(), String> {
+    if game.is_over() {
+        return Err("Cannot play if the game is over".to_string());
+    }
+
+    let pop_out = game.settings.variant == Variant::PopOut;
+    if kind == MoveKind::Pop && !pop_out {
+        return Err("Cannot pop a piece out in this variant".to_string());
+    }
+
+    let mut board = game.board.to_board(&game.settings)?;
+    let ply = ctx
+        .db
+        .game_move()
+        .game_round()
+        .filter((game.room_id, game.round))
+        .count() as u32;
+
+    let (row, win, full) = match kind {
+        MoveKind::Drop => {
+            let played = board
+                .play(column as usize, team_id)
+                .map_err(|e| e.to_string())?;
+            match played.outcome {
+                Some(Outcome::Win(line)) => (played.row, Some((team_id, line)), false),
+                Some(Outcome::Draw) => (played.row, None, true),
+                None => (played.row, None, false),
+            }
+        }
+        MoveKind::Pop => {
+            let win = board
+                .pop(column as usize, team_id)
+                .map_err(|e| e.to_string())?;
+            let row = game.settings.rows as usize - 1;
+            (row, win.map(|win| (win.team, win.line)), false)
+        }
+    };
+
+    // a full board only ends a classic game, pop outs can free it again
+    let mut drawn = full && !pop_out;
+    if pop_out {
+        let key = position_key(&board, team_id);
+        game.positions.push(key);
+        drawn |= game.positions.iter().filter(|&&k| k == key).count() >= REPETITIONS_FOR_DRAW;
+    }
+
+    game.board = GameBoard::from(&board);
+    end_dropper_turn(ctx, &game_current_team);
+    game.latest_move = Some(Coord {
+        x: row as u32,
+        y: column,
+    });
+    ctx.db.game_move().try_insert(GameMove {
+        id: 0,
+        game_id: game.room_id,
+        round: game.round,
+        ply,
+        kind,
+        column,
+        row: row as u32,
+        team_id,
+        dropper,
+        skin: skin::equipped_skin(ctx, dropper),
+        timestamp: ctx.timestamp,
+    })?;
+
+    if let Some((winner, line)) = win {
+        // a pop can complete a line of another team, which then wins
+        game.winner = Some(Winner {
+            team_id: winner,
+            coordinates: line
+                .into_iter()
+                .map(|(row, col)| Coord {
+                    x: row as u32,
+                    y: col as u32,
+                })
+                .collect(),
+        });
+        post_game_end(ctx, &game, GameEnd::Finished)?;
+        ctx.db.game().room_id().update(game);
+    } else if drawn {
+        game.drawn = true;
+        post_game_end(ctx, &game, GameEnd::Finished)?;
+        ctx.db.game().room_id().update(game);
+    } else {
+        // the game is saved first since the next team may be a bot that plays right away
+        let game = ctx.db.game().room_id().update(game);
+        game_switch_team(ctx, &game, game_current_team)?;
+    }
+
+    Ok(())
+}
+
+fn validate_can_join_or_create(ctx: &ReducerContext) -> Result<JoinRoom, String> {
+    let jr = auth::room_member(ctx)?;
+
+    // a member of a team only moves within the game they already play
+    if ctx
+        .db
+        .join_team()
+        .joiner()
+        .find(ctx.sender)
+        .is_some_and(|jt| jt.room_id == jr.room_id)
+    {
+        return Ok(jr);
+    }
+
+    let room = ctx
+        .db
+        .room()
+        .id()
+        .find(jr.room_id)
+        .ok_or("Room does not exist")?;
+    let in_progress = ctx
+        .db
+        .game()
+        .room_id()
+        .find(jr.room_id)
+        .is_some_and(|game| game.is_in_progress());
+    match room.spectator_join {
+        SpectatorJoin::Never if jr.spectator => {
+            return Err("Cannot join the game as a spectator of this room".to_string())
+        }
+        SpectatorJoin::Anytime => {}
+        _ if !in_progress => {}
+        _ => return Err("Cannot join the game while it is in progress".to_string()),
+    }
+
     Ok(jr)
 }
 
 const FACIAL_EMOJIS: &str = "😀😃😄😁😆🥹😅😂🤣🥲☺️😊😇🙂🙃😉😌😍🥰😘😗😙😚😋😛😝😜🤪🤨🧐🤓😎🥸🤩🥳😏😒😞😔😟😕🙁☹️😣😖😫😩🥺😢😭😤😠😡🤬🤯😳🥵🥶😶‍🌫️😱😨😰😥😓🤗🤔🫣🤭🫢🫡🤫🫠🤥😶🫥😐🫤😑😬🙄😯😦😧😮😲🥱😴🤤😪😮‍💨😵😵‍💫🤐🥴🤢🤮🤧😷🤒🤕🤑🤠😈👿👹👺🤡💩👻💀☠️👽👾🤖🎃😺😸😹😻😼😽🙀😿😾🙈🙉🙊";
 
 #[reducer]
-pub fn create_game(ctx: &ReducerContext) -> Result<(), String> {
+pub fn create_game(ctx: &ReducerContext, settings: GameSettings) -> Result<(), String> {
     let jr = validate_can_join_or_create(ctx)?;
+    settings.validate()?;
 
     if ctx.db.game().room_id().find(jr.room_id).is_some() {
         return Err("Cannot create a game when one already exists in a room".to_string());
     }
 
-    let game = ctx.db.game().try_insert(Game::new(jr.room_id))?;
+    let mut game = ctx.db.game().try_insert(Game::new(
+        jr.room_id,
+        first_free_round(ctx, jr.room_id),
+        settings,
+        vec![],
+        0,
+    ))?;
 
     let emojis = FACIAL_EMOJIS
         .graphemes(true) // true for extended grapheme clusters
-        .choose_multiple(&mut ctx.rng(), 2);
-    let team1 = ctx.db.team().try_insert(Team {
-        id: 0,
-        game_id: game.room_id,
-        name: emojis[0].into(),
-    })?;
-    let team2 = ctx.db.team().try_insert(Team {
-        id: 0,
-        game_id: game.room_id,
-        name: emojis[1].into(),
-    })?;
+        .choose_multiple(&mut ctx.rng(), game.settings.teams as usize);
+    let mut team_ids = vec![];
+    for (i, emoji) in emojis.into_iter().enumerate() {
+        let is_last = i + 1 == game.settings.teams as usize;
+        let team = ctx.db.team().try_insert(Team {
+            id: 0,
+            game_id: game.room_id,
+            name: emoji.into(),
+            time_left: total_time(&game.settings),
+            bot: game.settings.bot.filter(|_| is_last),
+            eliminated: false,
+            last_dropper: None,
+            // the creator joins the first team
+            had_player: i == 0,
+        })?;
+        team_ids.push(team.id);
+    }
+    let creator_team_id = team_ids[0];
 
-    let start_team_id = if ctx.rng().gen_bool(0.5) {
-        team1.id
-    } else {
-        team2.id
-    };
+    team_ids.shuffle(&mut ctx.rng());
+    game.starting_team_id = team_ids[0];
+    game.turn_order = team_ids;
+    let game = ctx.db.game().room_id().update(game);
 
     ctx.db.game_current_team().try_insert(GameCurrentTeam {
         game_id: game.room_id,
-        team_id: start_team_id,
+        team_id: game.starting_team_id,
+        turn_started_at: ctx.timestamp,
+        deadline: None,
+        dropper: None,
+        vote_deadline: None,
     })?;
 
     ctx.db.join_team().try_insert(JoinTeam {
         room_id: jr.room_id,
         joiner: ctx.sender,
-        team_id: team1.id,
+        team_id: creator_team_id,
+        joined_at: ctx.timestamp,
     })?;
+    set_spectator(ctx, jr, false);
+    update_dropper(ctx, game.room_id);
+    update_first_turn_clock(ctx, game.room_id)?;
 
-    Ok(())
+    play_bot_turn(ctx, game.room_id)
 }
 
 #[reducer]
 pub fn join_to_team(ctx: &ReducerContext, team_id: u32) -> Result<(), String> {
     let jr = validate_can_join_or_create(ctx)?;
 
-    let Some(team) = ctx.db.team().id().find(team_id) else {
-        return Err("Cannot join to a game when team does not exist".to_string());
-    };
+    // the team id comes from the client and may belong to any room
+    let team = auth::team_in_room(ctx, &jr, team_id)?;
+
+    if team.bot.is_some() {
+        return Err("Cannot join to a team played by the bot".to_string());
+    }
+
+    if team.eliminated {
+        return Err("Cannot join to a team that is out of the game".to_string());
+    }
+
+    let game = ctx
+        .db
+        .game()
+        .room_id()
+        .find(team.game_id)
+        .ok_or("Cannot find game")?;
+    let members = ctx.db.join_team().team_id().filter(team.id).count() as u32;
+    if game
+        .settings
+        .max_team_size
+        .is_some_and(|max_team_size| members >= max_team_size)
+    {
+        return Err("Cannot join to a team that is full".to_string());
+    }
 
     if let Some(jt) = ctx.db.join_team().joiner().find(ctx.sender) {
         if jt.team_id == team.id {
             return Err("Cannot join to the same team".to_string());
         }
+        if game.is_in_progress() {
+            return Err("Cannot switch teams once the first piece has been dropped".to_string());
+        }
         ctx.db.join_team().joiner().update(JoinTeam {
             team_id: team.id,
+            joined_at: ctx.timestamp,
             ..jt
         });
     } else {
@@ -672,26 +2448,86 @@ pub fn join_to_team(ctx: &ReducerContext, team_id: u32) -> Result<(), String> {
             room_id: jr.room_id,
             joiner: ctx.sender,
             team_id: team.id,
+            joined_at: ctx.timestamp,
         })?;
     }
+    if !team.had_player {
+        ctx.db.team().id().update(Team {
+            had_player: true,
+            ..team
+        });
+    }
+    let room_id = jr.room_id;
+    set_spectator(ctx, jr, false);
+    update_dropper(ctx, room_id);
+    update_first_turn_clock(ctx, room_id)
+}
 
-    Ok(())
+/// Joins the team with the fewest players that can still take one
+#[reducer]
+pub fn auto_join_team(ctx: &ReducerContext) -> Result<(), String> {
+    let jr = validate_can_join_or_create(ctx)?;
+
+    if ctx.db.join_team().joiner().find(ctx.sender).is_some() {
+        return Err("Cannot join a team automatically when already in one".to_string());
+    }
+
+    let Some(game) = ctx.db.game().room_id().find(jr.room_id) else {
+        return Err("Cannot join to a team when there is no game".to_string());
+    };
+
+    let smallest_team = ctx
+        .db
+        .team()
+        .game_id()
+        .filter(game.room_id)
+        .filter(|team| team.bot.is_none() && !team.eliminated)
+        .map(|team| {
+            let members = ctx.db.join_team().team_id().filter(team.id).count() as u32;
+            (members, team.id)
+        })
+        .filter(|&(members, _)| {
+            game.settings
+                .max_team_size
+                .is_none_or(|max_team_size| members < max_team_size)
+        })
+        .min();
+    let Some((_, team_id)) = smallest_team else {
+        return Err("Cannot find a team that is not full".to_string());
+    };
+
+    join_to_team(ctx, team_id)
 }
 
 #[reducer]
 pub fn send_message(ctx: &ReducerContext, text: String) -> Result<(), String> {
     validate_message_text(&text)?;
-    if let Some(jr) = ctx.db.join_room().joiner().find(ctx.sender) {
-        ctx.db.message().try_insert(Message {
-            room_id: jr.room_id,
-            sender: ctx.sender,
-            sent_at: ctx.timestamp,
-            text,
-        })?;
-        Ok(())
-    } else {
-        Err("Cannot send message when not in a room".to_string())
+    let jr = auth::room_member(ctx)?;
+    ctx.db.message().try_insert(Message {
+        room_id: jr.room_id,
+        sender: ctx.sender,
+        sent_at: ctx.timestamp,
+        text,
+        spectators_only: false,
+    })?;
+    Ok(())
+}
+
+#[reducer]
+pub fn send_spectator_message(ctx: &ReducerContext, text: String) -> Result<(), String> {
+    validate_message_text(&text)?;
+    let jr = auth::room_member(ctx)?;
+    if !jr.spectator {
+        return Err("Cannot send message to the spectators when not spectating".to_string());
     }
+    ctx.db.message().try_insert(Message {
+        room_id: jr.room_id,
+        sender: ctx.sender,
+        sent_at: ctx.timestamp,
+        text,
+        spectators_only: true,
+    })?;
+    Ok(())
 }
 
 fn validate_message_text(text: &str) -> Result<(), String> {
@@ -727,13 +2563,61 @@ pub fn create_room(ctx: &ReducerContext, title: String) -> Result<(), String> {
         title,
         created_at: ctx.timestamp,
         owner: ctx.sender,
+        spectators: 0,
+        spectator_join: SpectatorJoin::BetweenGames,
+        start_policy: StartPolicy::LoserStarts,
     })?;
     join_to_room(ctx, room.id)
 }
 
 #[reducer]
 pub fn join_to_room(ctx: &ReducerContext, room_id: u32) -> Result<(), String> {
-    if ctx.db.join_room().joiner().find(&ctx.sender).is_some() {
+    enter_room(ctx, room_id, false)
+}
+
+/// Joins a room to watch its games without being able to disturb them
+#[reducer]
+pub fn spectate_room(ctx: &ReducerContext, room_id: u32) -> Result<(), String> {
+    enter_room(ctx, room_id, true)
+}
+
+/// Leaves the team of the sender to watch the rest of the game
+#[reducer]
+pub fn become_spectator(ctx: &ReducerContext) -> Result<(), String> {
+    let jr = auth::room_member(ctx)?;
+    if jr.spectator {
+        return Err("Already spectating".to_string());
+    }
+    leave_team(ctx)?;
+    set_spectator(ctx, jr, true);
+    Ok(())
+}
+
+#[reducer]
+pub fn set_spectator_join(
+    ctx: &ReducerContext,
+    spectator_join: SpectatorJoin,
+) -> Result<(), String> {
+    let room = auth::room_owner(ctx)?;
+    ctx.db.room().id().update(Room {
+        spectator_join,
+        ..room
+    });
+    Ok(())
+}
+
+#[reducer]
+pub fn set_start_policy(ctx: &ReducerContext, start_policy: StartPolicy) -> Result<(), String> {
+    let room = auth::room_owner(ctx)?;
+    ctx.db.room().id().update(Room {
+        start_policy,
+        ..room
+    });
+    Ok(())
+}
+
+fn enter_room(ctx: &ReducerContext, room_id: u32, spectator: bool) -> Result<(), String> {
+    if ctx.db.join_room().joiner().find(ctx.sender).is_some() {
         Err("Cannot join to a room when already in one".to_string())
     } else {
         if ctx.db.room().id().find(room_id).is_none() {
@@ -752,15 +2636,17 @@ pub fn join_to_room(ctx: &ReducerContext, room_id: u32) -> Result<(), String> {
             room_id,
             joiner: ctx.sender,
             joined_at: ctx.timestamp,
+            spectator,
         })?;
+        update_spectator_count(ctx, room_id);
         Ok(())
     }
 }
 
 #[reducer]
-pub fn leave_room(ctx: &ReducerContext) {
+pub fn leave_room(ctx: &ReducerContext) -> Result<(), String> {
     delete_join_room(ctx, DeleteJoinRoomBy::Joiner(ctx.sender));
-    leave_team(ctx);
+    leave_team(ctx)?;
     if let Some(room) = ctx.db.room().owner().find(ctx.sender) {
         if let Some(other_jr) = ctx.db.join_room().room_id().filter(room.id).next() {
             // Promote the next player to owner
@@ -773,6 +2659,8 @@ pub fn leave_room(ctx: &ReducerContext) {
             delete_room(ctx, DeleteRoomBy::RoomId(room.id));
         }
     }
+
+    Ok(())
 }
 
 #[reducer(init)]
@@ -794,6 +2682,15 @@ pub fn init(ctx: &ReducerContext) -> Result<(), String> {
             scheduled_at: ScheduleAt::Interval(TimeDuration::from_duration(one_day)),
         })?;
 
+    ctx.db.rating_decay_timer().try_insert(RatingDecayTimer {
+        scheduled_id: 0,
+        scheduled_at: ScheduleAt::Interval(TimeDuration::from_duration(one_day)),
+    })?;
+
+    leaderboard::start_season(ctx)?;
+    leaderboard::schedule_expiry(ctx)?;
+    skin::seed_catalog(ctx)?;
+
     Ok(())
 }
 
@@ -811,8 +2708,18 @@ pub fn identity_connected(ctx: &ReducerContext) {
             identity: ctx.sender,
             name: None,
             online: true,
+            skin: None,
         });
     }
+
+    if let Some(jt) = ctx.db.join_team().joiner().find(ctx.sender) {
+        update_dropper(ctx, jt.room_id);
+    }
+
+    // `init` does not run again when the module is republished
+    if let Err(error) = skin::seed_catalog(ctx) {
+        log::warn!("Cannot seed the skin catalog: {}", error);
+    }
 }
 
 #[reducer(client_disconnected)]
@@ -828,6 +2735,25 @@ pub fn identity_disconnected(ctx: &ReducerContext) {
             "Disconnected player not found in database with identity {:?}",
             ctx.sender
         );
+        return;
+    }
+
+    // give the team some time to come back before forfeiting the game
+    let Some(jt) = ctx.db.join_team().joiner().find(ctx.sender) else {
+        return;
+    };
+    update_dropper(ctx, jt.room_id);
+    let Some(game) = ctx.db.game().room_id().find(jt.room_id) else {
+        return;
+    };
+    if clock_running(ctx, &game) {
+        ctx.db.forfeit_timer().insert(ForfeitTimer {
+            scheduled_id: 0,
+            scheduled_at: ScheduleAt::Time(ctx.timestamp + FORFEIT_GRACE_PERIOD),
+            game_id: game.room_id,
+            round: game.round,
+            team_id: jt.team_id,
+        });
     }
 }
 
@@ -0,0 +1,211 @@
+//! Glicko-2 ratings, see <http://www.glicko.net/glicko/glicko2.pdf>. A team is rated as a single
+//! player whose rating is the average of its members'.
+
+use std::f64::consts::PI;
+
+/// Converts between the Glicko scale and the Glicko-2 one
+const SCALE: f64 = 173.7178;
+/// Constrains how much the volatility can change between games
+const TAU: f64 = 0.5;
+/// Tolerance of the volatility iteration
+const EPSILON: f64 = 0.000001;
+
+pub(crate) const DEFAULT_RATING: f64 = 1500.0;
+/// Deviation of a new player, also the most a deviation can grow to
+pub(crate) const MAX_DEVIATION: f64 = 350.0;
+pub(crate) const DEFAULT_VOLATILITY: f64 = 0.06;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct Rating {
+    pub(crate) rating: f64,
+    pub(crate) deviation: f64,
+    pub(crate) volatility: f64,
+}
+
+impl Default for Rating {
+    fn default() -> Self {
+        Self {
+            rating: DEFAULT_RATING,
+            deviation: MAX_DEVIATION,
+            volatility: DEFAULT_VOLATILITY,
+        }
+    }
+}
+
+impl Rating {
+    fn mu(&self) -> f64 {
+        (self.rating - DEFAULT_RATING) / SCALE
+    }
+
+    fn phi(&self) -> f64 {
+        self.deviation / SCALE
+    }
+
+    /// A player that has not played for a rating period gets less certain about their rating
+    pub(crate) fn decay(&self) -> Self {
+        let phi = (self.phi().powi(2) + self.volatility.powi(2)).sqrt();
+        Self {
+            deviation: (phi * SCALE).min(MAX_DEVIATION),
+            ..*self
+        }
+    }
+
+    /// Rates a game of a player of a team of `team_size` players against other teams, given as
+    /// their rating and the score of the player's team against them: 1 for a win, 0.5 for a draw
+    /// and 0 for a loss. The change of rating is shared by the members of the team.
+    pub(crate) fn rate(&self, team_size: usize, opponents: &[(Rating, f64)]) -> Self {
+        if opponents.is_empty() {
+            return self.decay();
+        }
+
+        let mu = self.mu();
+        let phi = self.phi();
+
+        let mut inverse_variance = 0.0;
+        let mut improvement = 0.0;
+        for (opponent, score) in opponents {
+            let g = g(opponent.phi());
+            let expected = 1.0 / (1.0 + (-g * (mu - opponent.mu())).exp());
+            inverse_variance += g * g * expected * (1.0 - expected);
+            improvement += g * (score - expected);
+        }
+        let variance = 1.0 / inverse_variance;
+        let delta = variance * improvement;
+
+        let volatility = self.new_volatility(phi, variance, delta);
+        let phi_star = (phi * phi + volatility * volatility).sqrt();
+        let new_phi = 1.0 / (1.0 / (phi_star * phi_star) + 1.0 / variance).sqrt();
+        let new_mu = mu + new_phi * new_phi * improvement / team_size.max(1) as f64;
+
+        Self {
+            rating: new_mu * SCALE + DEFAULT_RATING,
+            deviation: (new_phi * SCALE).min(MAX_DEVIATION),
+            volatility,
+        }
+    }
+
+    /// Step 5 of the paper, the Illinois algorithm
+    fn new_volatility(&self, phi: f64, variance: f64, delta: f64) -> f64 {
+        let a = (self.volatility * self.volatility).ln();
+        let f = |x: f64| {
+            let ex = x.exp();
+            let d = phi * phi + variance + ex;
+            ex * (delta * delta - d) / (2.0 * d * d) - (x - a) / (TAU * TAU)
+        };
+
+        let mut big_a = a;
+        let mut big_b = if delta * delta > phi * phi + variance {
+            (delta * delta - phi * phi - variance).ln()
+        } else {
+            let mut k = 1.0;
+            while f(a - k * TAU) < 0.0 {
+                k += 1.0;
+            }
+            a - k * TAU
+        };
+        let mut f_a = f(big_a);
+        let mut f_b = f(big_b);
+        while (big_b - big_a).abs() > EPSILON {
+            let big_c = big_a + (big_a - big_b) * f_a / (f_b - f_a);
+            let f_c = f(big_c);
+            if f_c * f_b <= 0.0 {
+                big_a = big_b;
+                f_a = f_b;
+            } else {
+                f_a /= 2.0;
+            }
+            big_b = big_c;
+            f_b = f_c;
+        }
+        (big_a / 2.0).exp()
+    }
+}
+
+fn g(phi: f64) -> f64 {
+    1.0 / (1.0 + 3.0 * phi * phi / (PI * PI)).sqrt()
+}
+
+/// Rating of a team as a single player: the average rating, and the deviation of that average
+pub(crate) fn team_rating(members: &[Rating]) -> Rating {
+    if members.is_empty() {
+        return Rating::default();
+    }
+    let n = members.len() as f64;
+    let rating = members.iter().map(|m| m.rating).sum::<f64>() / n;
+    let deviation = members
+        .iter()
+        .map(|m| m.deviation * m.deviation)
+        .sum::<f64>()
+        .sqrt()
+        / n;
+    let volatility = members.iter().map(|m| m.volatility).sum::<f64>() / n;
+    Rating {
+        rating,
+        deviation,
+        volatility,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rating(rating: f64, deviation: f64) -> Rating {
+        Rating {
+            rating,
+            deviation,
+            volatility: DEFAULT_VOLATILITY,
+        }
+    }
+
+    #[test]
+    fn example_of_the_paper() {
+        let player = rating(1500.0, 200.0);
+        let rated = player.rate(
+            1,
+            &[
+                (rating(1400.0, 30.0), 1.0),
+                (rating(1550.0, 100.0), 0.0),
+                (rating(1700.0, 300.0), 0.0),
+            ],
+        );
+        assert!((rated.rating - 1464.06).abs() < 0.01, "{}", rated.rating);
+        assert!(
+            (rated.deviation - 151.52).abs() < 0.01,
+            "{}",
+            rated.deviation
+        );
+        assert!((rated.volatility - 0.05999).abs() < 0.00001);
+    }
+
+    #[test]
+    fn bigger_teams_share_the_change() {
+        let player = Rating::default();
+        let opponent = [(Rating::default(), 1.0)];
+        let alone = player.rate(1, &opponent).rating - DEFAULT_RATING;
+        let in_pair = player.rate(2, &opponent).rating - DEFAULT_RATING;
+        assert!(alone > 0.0);
+        assert!((alone / 2.0 - in_pair).abs() < 1e-9);
+    }
+
+    #[test]
+    fn beating_a_stronger_team_counts_more() {
+        let player = rating(1500.0, 100.0);
+        let weak = team_rating(&[rating(1300.0, 100.0), rating(1400.0, 100.0)]);
+        let strong = team_rating(&[rating(1600.0, 100.0), rating(1700.0, 100.0)]);
+        assert_eq!(weak.rating, 1350.0);
+        assert!(player.rate(1, &[(strong, 1.0)]).rating > player.rate(1, &[(weak, 1.0)]).rating);
+    }
+
+    #[test]
+    fn inactivity_increases_the_deviation_up_to_the_max() {
+        let mut player = rating(1800.0, 50.0);
+        let decayed = player.decay();
+        assert_eq!(decayed.rating, 1800.0);
+        assert!(decayed.deviation > 50.0);
+        for _ in 0..100_000 {
+            player = player.decay();
+        }
+        assert_eq!(player.deviation, MAX_DEVIATION);
+    }
+}
@@ -0,0 +1,151 @@
+//! Skins of the coins, bought with the coins of the wallet and shown on every piece the player
+//! drops.
+
+use spacetimedb::{
+    client_visibility_filter, reducer, table, Filter, Identity, ReducerContext, SpacetimeType,
+    Table, Timestamp,
+};
+
+use crate::{
+    player,
+    wallet::{self, CoinReason},
+    Player,
+};
+
+#[derive(SpacetimeType, Clone, Copy, PartialEq)]
+pub enum Rarity {
+    Common,
+    Rare,
+    Epic,
+    Legendary,
+}
+
+/// Skins of the catalog, added to the `skin` table when the module is first published and, for
+/// the ones added later, when a client connects
+const CATALOG: &[(&str, u64, Rarity)] = &[
+    ("Copper", 50, Rarity::Common),
+    ("Silver", 100, Rarity::Common),
+    ("Gold", 250, Rarity::Rare),
+    ("Emerald", 400, Rarity::Rare),
+    ("Ruby", 750, Rarity::Epic),
+    ("Sapphire", 750, Rarity::Epic),
+    ("Galaxy", 2000, Rarity::Legendary),
+];
+
+#[table(name = skin, public)]
+pub struct Skin {
+    #[primary_key]
+    #[auto_inc]
+    id: u32,
+    #[unique]
+    name: String,
+    /// in coins
+    price: u64,
+    rarity: Rarity,
+}
+
+/// Skins a player has bought
+#[table(
+    name = owned_skin,
+    public,
+    index(name = owner_skin, btree(columns = [owner, skin_id]))
+)]
+pub struct OwnedSkin {
+    #[primary_key]
+    #[auto_inc]
+    id: u64,
+    owner: Identity,
+    skin_id: u32,
+    purchased_at: Timestamp,
+}
+
+/// A player can only see their own skins, the equipped one is on `player`
+#[client_visibility_filter]
+const OWNED_SKIN_FILTER: Filter = Filter::Sql("SELECT * FROM owned_skin WHERE owner = :sender");
+
+/// Adds the skins of the catalog that are missing, so that new entries of `CATALOG` reach a
+/// database published before them
+pub(crate) fn seed_catalog(ctx: &ReducerContext) -> Result<(), String> {
+    for (name, price, rarity) in CATALOG {
+        if ctx.db.skin().name().find(name.to_string()).is_none() {
+            ctx.db.skin().try_insert(Skin {
+                id: 0,
+                name: name.to_string(),
+                price: *price,
+                rarity: *rarity,
+            })?;
+        }
+    }
+    Ok(())
+}
+
+fn owns_skin(ctx: &ReducerContext, owner: Identity, skin_id: u32) -> bool {
+    ctx.db
+        .owned_skin()
+        .owner_skin()
+        .filter((owner, skin_id))
+        .next()
+        .is_some()
+}
+
+/// Skin the player has equipped, to be shown on the pieces they drop
+pub(crate) fn equipped_skin(ctx: &ReducerContext, player: Identity) -> Option<u32> {
+    ctx.db
+        .player()
+        .identity()
+        .find(player)
+        .and_then(|player| player.skin)
+}
+
+#[reducer]
+pub fn purchase_skin(ctx: &ReducerContext, skin_id: u32) -> Result<(), String> {
+    let skin = ctx
+        .db
+        .skin()
+        .id()
+        .find(skin_id)
+        .ok_or("Skin does not exist")?;
+    if owns_skin(ctx, ctx.sender, skin_id) {
+        return Err("Skin is already owned".to_string());
+    }
+
+    // fails without buying the skin when the wallet does not have enough coins
+    let key = format!("skin:{}:{}", ctx.sender, skin_id);
+    if !wallet::transact(
+        ctx,
+        ctx.sender,
+        -(skin.price as i64),
+        CoinReason::Purchase,
+        key,
+    )? {
+        return Err("Skin has already been paid for".to_string());
+    }
+    ctx.db.owned_skin().try_insert(OwnedSkin {
+        id: 0,
+        owner: ctx.sender,
+        skin_id,
+        purchased_at: ctx.timestamp,
+    })?;
+    Ok(())
+}
+
+/// `None` goes back to the default coin
+#[reducer]
+pub fn equip_skin(ctx: &ReducerContext, skin_id: Option<u32>) -> Result<(), String> {
+    let player = ctx
+        .db
+        .player()
+        .identity()
+        .find(ctx.sender)
+        .ok_or("Player does not exist")?;
+    if let Some(skin_id) = skin_id {
+        if !owns_skin(ctx, ctx.sender, skin_id) {
+            return Err("Skin is not owned".to_string());
+        }
+    }
+    ctx.db.player().identity().update(Player {
+        skin: skin_id,
+        ..player
+    });
+    Ok(())
+}
@@ -0,0 +1,238 @@
+//! Coins of the players. A balance only changes along with a new row of the ledger, whose key
+//! makes sure the same credit or debit is never applied twice.
+
+use std::fmt;
+
+use spacetimedb::{
+    client_visibility_filter, table, Filter, Identity, ReducerContext, SpacetimeType, Table,
+    Timestamp,
+};
+
+use crate::GameOutcome;
+
+const WIN_COINS: u64 = 20;
+const DRAW_COINS: u64 = 10;
+/// for a game played to the end, won or not
+const PARTICIPATION_COINS: u64 = 5;
+/// per win in a row after the first one
+const STREAK_COINS: u64 = 5;
+const MAX_STREAK_COINS: u64 = 25;
+
+#[table(name = wallet, public)]
+pub struct Wallet {
+    #[primary_key]
+    owner: Identity,
+    balance: u64,
+    /// games won in a row, ended by any other outcome
+    win_streak: u32,
+    /// last archived game the player has been paid for
+    rewarded_through: u32,
+}
+
+/// A player can only see their own wallet
+#[client_visibility_filter]
+const WALLET_FILTER: Filter = Filter::Sql("SELECT * FROM wallet WHERE owner = :sender");
+
+#[derive(SpacetimeType, Clone, Copy, PartialEq, Debug)]
+pub enum CoinReason {
+    Win,
+    Draw,
+    Participation,
+    /// bonus for winning several games in a row
+    Streak,
+    /// a skin was bought
+    Purchase,
+}
+
+/// Rows are only ever inserted
+#[table(name = coin_transaction, public)]
+pub struct CoinTransaction {
+    #[primary_key]
+    #[auto_inc]
+    id: u64,
+    #[index(btree)]
+    player: Identity,
+    /// identifies what the coins were given or taken for, a second transaction with the same key
+    /// is not applied
+    #[unique]
+    key: String,
+    /// negative when coins are spent
+    amount: i64,
+    reason: CoinReason,
+    /// balance of the wallet once the transaction is applied
+    balance: u64,
+    timestamp: Timestamp,
+}
+
+/// A player can only see their own transactions
+#[client_visibility_filter]
+const COIN_TRANSACTION_FILTER: Filter =
+    Filter::Sql("SELECT * FROM coin_transaction WHERE player = :sender");
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum WalletError {
+    NotEnoughCoins,
+    Overflow,
+}
+
+impl fmt::Display for WalletError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let message = match self {
+            WalletError::NotEnoughCoins => "Not enough coins",
+            WalletError::Overflow => "Too many coins",
+        };
+        f.write_str(message)
+    }
+}
+
+impl From<WalletError> for String {
+    fn from(error: WalletError) -> Self {
+        error.to_string()
+    }
+}
+
+/// Balance once `amount` is added to it
+fn apply(balance: u64, amount: i64) -> Result<u64, WalletError> {
+    if amount >= 0 {
+        balance
+            .checked_add(amount.unsigned_abs())
+            .ok_or(WalletError::Overflow)
+    } else {
+        balance
+            .checked_sub(amount.unsigned_abs())
+            .ok_or(WalletError::NotEnoughCoins)
+    }
+}
+
+fn find_or_create_wallet(ctx: &ReducerContext, owner: Identity) -> Result<Wallet, String> {
+    match ctx.db.wallet().owner().find(owner) {
+        Some(wallet) => Ok(wallet),
+        None => Ok(ctx.db.wallet().try_insert(Wallet {
+            owner,
+            balance: 0,
+            win_streak: 0,
+            rewarded_through: 0,
+        })?),
+    }
+}
+
+/// Adds `amount` to the balance of the player unless a transaction with `key` already exists.
+/// Returns whether the transaction has been applied.
+pub(crate) fn transact(
+    ctx: &ReducerContext,
+    player: Identity,
+    amount: i64,
+    reason: CoinReason,
+    key: String,
+) -> Result<bool, String> {
+    if ctx.db.coin_transaction().key().find(&key).is_some() {
+        return Ok(false);
+    }
+    let wallet = find_or_create_wallet(ctx, player)?;
+    let balance = apply(wallet.balance, amount)?;
+    ctx.db.coin_transaction().try_insert(CoinTransaction {
+        id: 0,
+        player,
+        key,
+        amount,
+        reason,
+        balance,
+        timestamp: ctx.timestamp,
+    })?;
+    ctx.db.wallet().owner().update(Wallet { balance, ..wallet });
+    Ok(true)
+}
+
+/// Coins earned for a game, `win_streak` counting that game
+fn game_rewards(outcome: GameOutcome, win_streak: u32) -> Vec<(CoinReason, u64)> {
+    let mut rewards = Vec::new();
+    match outcome {
+        GameOutcome::Win => {
+            rewards.push((CoinReason::Win, WIN_COINS));
+            if win_streak > 1 {
+                let bonus = (STREAK_COINS * (win_streak as u64 - 1)).min(MAX_STREAK_COINS);
+                rewards.push((CoinReason::Streak, bonus));
+            }
+        }
+        GameOutcome::Draw => rewards.push((CoinReason::Draw, DRAW_COINS)),
+        GameOutcome::Loss | GameOutcome::Forfeit | GameOutcome::Abandoned => {}
+    }
+    if matches!(
+        outcome,
+        GameOutcome::Win | GameOutcome::Draw | GameOutcome::Loss
+    ) {
+        rewards.push((CoinReason::Participation, PARTICIPATION_COINS));
+    }
+    rewards
+}
+
+/// Pays the player for the game archived as `archive_id`. Paying twice for the same game, or for
+/// an older one, does nothing.
+pub(crate) fn reward_game(
+    ctx: &ReducerContext,
+    player: Identity,
+    archive_id: u32,
+    outcome: GameOutcome,
+) -> Result<(), String> {
+    let wallet = find_or_create_wallet(ctx, player)?;
+    if archive_id <= wallet.rewarded_through {
+        return Ok(());
+    }
+    let win_streak = match outcome {
+        GameOutcome::Win => wallet.win_streak + 1,
+        GameOutcome::Abandoned => wallet.win_streak,
+        _ => 0,
+    };
+
+    for (reason, amount) in game_rewards(outcome, win_streak) {
+        let key = format!("game:{}:{}:{:?}", archive_id, player, reason);
+        transact(ctx, player, amount as i64, reason, key)?;
+    }
+
+    let wallet = find_or_create_wallet(ctx, player)?;
+    ctx.db.wallet().owner().update(Wallet {
+        win_streak,
+        rewarded_through: archive_id,
+        ..wallet
+    });
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn balance_never_goes_negative() {
+        assert_eq!(apply(10, -10), Ok(0));
+        assert_eq!(apply(10, -11), Err(WalletError::NotEnoughCoins));
+        assert_eq!(apply(u64::MAX, 1), Err(WalletError::Overflow));
+        assert_eq!(apply(0, i64::MIN), Err(WalletError::NotEnoughCoins));
+    }
+
+    #[test]
+    fn streak_bonus_grows_up_to_a_max() {
+        let total = |streak| {
+            game_rewards(GameOutcome::Win, streak)
+                .iter()
+                .map(|(_, coins)| coins)
+                .sum::<u64>()
+        };
+        assert_eq!(total(1), WIN_COINS + PARTICIPATION_COINS);
+        assert_eq!(total(2), WIN_COINS + PARTICIPATION_COINS + STREAK_COINS);
+        assert_eq!(
+            total(100),
+            WIN_COINS + PARTICIPATION_COINS + MAX_STREAK_COINS
+        );
+    }
+
+    #[test]
+    fn nothing_for_leaving_a_game() {
+        assert!(game_rewards(GameOutcome::Forfeit, 0).is_empty());
+        assert!(game_rewards(GameOutcome::Abandoned, 0).is_empty());
+        assert_eq!(
+            game_rewards(GameOutcome::Loss, 0),
+            vec![(CoinReason::Participation, PARTICIPATION_COINS)]
+        );
+    }
+}
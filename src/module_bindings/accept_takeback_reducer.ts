@@ -9,34 +9,34 @@ import {
   AlgebraicValue,
   BinaryReader,
   BinaryWriter,
-  CallReducerFlags,
   ConnectionId,
   DbConnectionBuilder,
   DbConnectionImpl,
-  DbContext,
-  ErrorContextInterface,
-  Event,
-  EventContextInterface,
   Identity,
   ProductType,
   ProductTypeElement,
-  ReducerEventContextInterface,
   SubscriptionBuilderImpl,
-  SubscriptionEventContextInterface,
   SumType,
   SumTypeVariant,
   TableCache,
   TimeDuration,
   Timestamp,
   deepEqual,
+  type CallReducerFlags,
+  type DbContext,
+  type ErrorContextInterface,
+  type Event,
+  type EventContextInterface,
+  type ReducerEventContextInterface,
+  type SubscriptionEventContextInterface,
 } from "@clockworklabs/spacetimedb-sdk";
 
-export type RestartGameHasWinner = {};
+export type AcceptTakeback = {};
 
 /**
  * A namespace for generated helper functions.
  */
-export namespace RestartGameHasWinner {
+export namespace AcceptTakeback {
   /**
   * A function which returns this type represented as an AlgebraicType.
   * This function is derived from the AlgebraicType used to generate this type.
@@ -46,12 +46,12 @@ export namespace RestartGameHasWinner {
     ]);
   }
 
-  export function serialize(writer: BinaryWriter, value: RestartGameHasWinner): void {
-    RestartGameHasWinner.getTypeScriptAlgebraicType().serialize(writer, value);
+  export function serialize(writer: BinaryWriter, value: AcceptTakeback): void {
+    AcceptTakeback.getTypeScriptAlgebraicType().serialize(writer, value);
   }
 
-  export function deserialize(reader: BinaryReader): RestartGameHasWinner {
-    return RestartGameHasWinner.getTypeScriptAlgebraicType().deserialize(reader);
+  export function deserialize(reader: BinaryReader): AcceptTakeback {
+    return AcceptTakeback.getTypeScriptAlgebraicType().deserialize(reader);
   }
 
 }
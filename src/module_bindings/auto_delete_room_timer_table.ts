@@ -9,29 +9,29 @@ import {
   AlgebraicValue,
   BinaryReader,
   BinaryWriter,
-  CallReducerFlags,
   ConnectionId,
   DbConnectionBuilder,
   DbConnectionImpl,
-  DbContext,
-  ErrorContextInterface,
-  Event,
-  EventContextInterface,
   Identity,
   ProductType,
   ProductTypeElement,
-  ReducerEventContextInterface,
   SubscriptionBuilderImpl,
-  SubscriptionEventContextInterface,
   SumType,
   SumTypeVariant,
   TableCache,
   TimeDuration,
   Timestamp,
   deepEqual,
+  type CallReducerFlags,
+  type DbContext,
+  type ErrorContextInterface,
+  type Event,
+  type EventContextInterface,
+  type ReducerEventContextInterface,
+  type SubscriptionEventContextInterface,
 } from "@clockworklabs/spacetimedb-sdk";
 import { AutoDeleteRoomTimer } from "./auto_delete_room_timer_type";
-import { EventContext, Reducer, RemoteReducers, RemoteTables } from ".";
+import { type EventContext, type Reducer, RemoteReducers, RemoteTables } from ".";
 
 /**
  * Table handle for the table `auto_delete_room_timer`.
@@ -58,22 +58,22 @@ export class AutoDeleteRoomTimerTableHandle {
     return this.tableCache.iter();
   }
   /**
-   * Access to the `scheduled_id` unique index on the table `auto_delete_room_timer`,
+   * Access to the `scheduledId` unique index on the table `auto_delete_room_timer`,
    * which allows point queries on the field of the same name
    * via the [`AutoDeleteRoomTimerScheduledIdUnique.find`] method.
    *
    * Users are encouraged not to explicitly reference this type,
    * but to directly chain method calls,
-   * like `ctx.db.autoDeleteRoomTimer.scheduled_id().find(...)`.
+   * like `ctx.db.autoDeleteRoomTimer.scheduledId().find(...)`.
    *
-   * Get a handle on the `scheduled_id` unique index on the table `auto_delete_room_timer`.
+   * Get a handle on the `scheduledId` unique index on the table `auto_delete_room_timer`.
    */
-  scheduled_id = {
-    // Find the subscribed row whose `scheduled_id` column value is equal to `col_val`,
+  scheduledId = {
+    // Find the subscribed row whose `scheduledId` column value is equal to `col_val`,
     // if such a row is present in the client cache.
     find: (col_val: bigint): AutoDeleteRoomTimer | undefined => {
       for (let row of this.tableCache.iter()) {
-        if (deepEqual(row.scheduled_id, col_val)) {
+        if (deepEqual(row.scheduledId, col_val)) {
           return row;
         }
       }
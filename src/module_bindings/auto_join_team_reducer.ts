@@ -9,34 +9,34 @@ import {
   AlgebraicValue,
   BinaryReader,
   BinaryWriter,
-  CallReducerFlags,
   ConnectionId,
   DbConnectionBuilder,
   DbConnectionImpl,
-  DbContext,
-  ErrorContextInterface,
-  Event,
-  EventContextInterface,
   Identity,
   ProductType,
   ProductTypeElement,
-  ReducerEventContextInterface,
   SubscriptionBuilderImpl,
-  SubscriptionEventContextInterface,
   SumType,
   SumTypeVariant,
   TableCache,
   TimeDuration,
   Timestamp,
   deepEqual,
+  type CallReducerFlags,
+  type DbContext,
+  type ErrorContextInterface,
+  type Event,
+  type EventContextInterface,
+  type ReducerEventContextInterface,
+  type SubscriptionEventContextInterface,
 } from "@clockworklabs/spacetimedb-sdk";
 
-export type RestartGameTableFull = {};
+export type AutoJoinTeam = {};
 
 /**
  * A namespace for generated helper functions.
  */
-export namespace RestartGameTableFull {
+export namespace AutoJoinTeam {
   /**
   * A function which returns this type represented as an AlgebraicType.
   * This function is derived from the AlgebraicType used to generate this type.
@@ -46,12 +46,12 @@ export namespace RestartGameTableFull {
     ]);
   }
 
-  export function serialize(writer: BinaryWriter, value: RestartGameTableFull): void {
-    RestartGameTableFull.getTypeScriptAlgebraicType().serialize(writer, value);
+  export function serialize(writer: BinaryWriter, value: AutoJoinTeam): void {
+    AutoJoinTeam.getTypeScriptAlgebraicType().serialize(writer, value);
   }
 
-  export function deserialize(reader: BinaryReader): RestartGameTableFull {
-    return RestartGameTableFull.getTypeScriptAlgebraicType().deserialize(reader);
+  export function deserialize(reader: BinaryReader): AutoJoinTeam {
+    return AutoJoinTeam.getTypeScriptAlgebraicType().deserialize(reader);
   }
 
 }
@@ -9,29 +9,29 @@ import {
   AlgebraicValue,
   BinaryReader,
   BinaryWriter,
-  CallReducerFlags,
   ConnectionId,
   DbConnectionBuilder,
   DbConnectionImpl,
-  DbContext,
-  ErrorContextInterface,
-  Event,
-  EventContextInterface,
   Identity,
   ProductType,
   ProductTypeElement,
-  ReducerEventContextInterface,
   SubscriptionBuilderImpl,
-  SubscriptionEventContextInterface,
   SumType,
   SumTypeVariant,
   TableCache,
   TimeDuration,
   Timestamp,
   deepEqual,
+  type CallReducerFlags,
+  type DbContext,
+  type ErrorContextInterface,
+  type Event,
+  type EventContextInterface,
+  type ReducerEventContextInterface,
+  type SubscriptionEventContextInterface,
 } from "@clockworklabs/spacetimedb-sdk";
 import { GameCurrentTeam } from "./game_current_team_type";
-import { EventContext, Reducer, RemoteReducers, RemoteTables } from ".";
+import { type EventContext, type Reducer, RemoteReducers, RemoteTables } from ".";
 
 /**
  * Table handle for the table `game_current_team`.
@@ -58,44 +58,44 @@ export class GameCurrentTeamTableHandle {
     return this.tableCache.iter();
   }
   /**
-   * Access to the `game_id` unique index on the table `game_current_team`,
+   * Access to the `gameId` unique index on the table `game_current_team`,
    * which allows point queries on the field of the same name
    * via the [`GameCurrentTeamGameIdUnique.find`] method.
    *
    * Users are encouraged not to explicitly reference this type,
    * but to directly chain method calls,
-   * like `ctx.db.gameCurrentTeam.game_id().find(...)`.
+   * like `ctx.db.gameCurrentTeam.gameId().find(...)`.
    *
-   * Get a handle on the `game_id` unique index on the table `game_current_team`.
+   * Get a handle on the `gameId` unique index on the table `game_current_team`.
    */
-  game_id = {
-    // Find the subscribed row whose `game_id` column value is equal to `col_val`,
+  gameId = {
+    // Find the subscribed row whose `gameId` column value is equal to `col_val`,
     // if such a row is present in the client cache.
     find: (col_val: number): GameCurrentTeam | undefined => {
       for (let row of this.tableCache.iter()) {
-        if (deepEqual(row.game_id, col_val)) {
+        if (deepEqual(row.gameId, col_val)) {
           return row;
         }
       }
     },
   };
   /**
-   * Access to the `team_id` unique index on the table `game_current_team`,
+   * Access to the `teamId` unique index on the table `game_current_team`,
    * which allows point queries on the field of the same name
    * via the [`GameCurrentTeamTeamIdUnique.find`] method.
    *
    * Users are encouraged not to explicitly reference this type,
    * but to directly chain method calls,
-   * like `ctx.db.gameCurrentTeam.team_id().find(...)`.
+   * like `ctx.db.gameCurrentTeam.teamId().find(...)`.
    *
-   * Get a handle on the `team_id` unique index on the table `game_current_team`.
+   * Get a handle on the `teamId` unique index on the table `game_current_team`.
    */
-  team_id = {
-    // Find the subscribed row whose `team_id` column value is equal to `col_val`,
+  teamId = {
+    // Find the subscribed row whose `teamId` column value is equal to `col_val`,
     // if such a row is present in the client cache.
     find: (col_val: number): GameCurrentTeam | undefined => {
       for (let row of this.tableCache.iter()) {
-        if (deepEqual(row.team_id, col_val)) {
+        if (deepEqual(row.teamId, col_val)) {
           return row;
         }
       }
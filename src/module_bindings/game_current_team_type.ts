@@ -9,30 +9,34 @@ import {
   AlgebraicValue,
   BinaryReader,
   BinaryWriter,
-  CallReducerFlags,
   ConnectionId,
   DbConnectionBuilder,
   DbConnectionImpl,
-  DbContext,
-  ErrorContextInterface,
-  Event,
-  EventContextInterface,
   Identity,
   ProductType,
   ProductTypeElement,
-  ReducerEventContextInterface,
   SubscriptionBuilderImpl,
-  SubscriptionEventContextInterface,
   SumType,
   SumTypeVariant,
   TableCache,
   TimeDuration,
   Timestamp,
   deepEqual,
+  type CallReducerFlags,
+  type DbContext,
+  type ErrorContextInterface,
+  type Event,
+  type EventContextInterface,
+  type ReducerEventContextInterface,
+  type SubscriptionEventContextInterface,
 } from "@clockworklabs/spacetimedb-sdk";
 export type GameCurrentTeam = {
   gameId: number,
   teamId: number,
+  turnStartedAt: Timestamp,
+  deadline: Timestamp | undefined,
+  dropper: Identity | undefined,
+  voteDeadline: Timestamp | undefined,
 };
 
 /**
@@ -47,6 +51,10 @@ export namespace GameCurrentTeam {
     return AlgebraicType.createProductType([
       new ProductTypeElement("gameId", AlgebraicType.createU32Type()),
       new ProductTypeElement("teamId", AlgebraicType.createU32Type()),
+      new ProductTypeElement("turnStartedAt", AlgebraicType.createTimestampType()),
+      new ProductTypeElement("deadline", AlgebraicType.createOptionType(AlgebraicType.createTimestampType())),
+      new ProductTypeElement("dropper", AlgebraicType.createOptionType(AlgebraicType.createIdentityType())),
+      new ProductTypeElement("voteDeadline", AlgebraicType.createOptionType(AlgebraicType.createTimestampType())),
     ]);
   }
 
@@ -9,32 +9,38 @@ import {
   AlgebraicValue,
   BinaryReader,
   BinaryWriter,
-  CallReducerFlags,
   ConnectionId,
   DbConnectionBuilder,
   DbConnectionImpl,
-  DbContext,
-  ErrorContextInterface,
-  Event,
-  EventContextInterface,
   Identity,
   ProductType,
   ProductTypeElement,
-  ReducerEventContextInterface,
   SubscriptionBuilderImpl,
-  SubscriptionEventContextInterface,
   SumType,
   SumTypeVariant,
   TableCache,
   TimeDuration,
   Timestamp,
   deepEqual,
+  type CallReducerFlags,
+  type DbContext,
+  type ErrorContextInterface,
+  type Event,
+  type EventContextInterface,
+  type ReducerEventContextInterface,
+  type SubscriptionEventContextInterface,
 } from "@clockworklabs/spacetimedb-sdk";
+import { GameOutcome as __GameOutcome } from "./game_outcome_type";
+
 export type GameHistory = {
   id: number,
   player: Identity,
-  won: boolean,
+  archiveId: number,
+  outcome: __GameOutcome,
+  wentFirst: boolean,
   timestamp: Timestamp,
+  day: number,
+  counted: boolean,
 };
 
 /**
@@ -49,8 +55,12 @@ export namespace GameHistory {
     return AlgebraicType.createProductType([
       new ProductTypeElement("id", AlgebraicType.createU32Type()),
       new ProductTypeElement("player", AlgebraicType.createIdentityType()),
-      new ProductTypeElement("won", AlgebraicType.createBoolType()),
+      new ProductTypeElement("archiveId", AlgebraicType.createU32Type()),
+      new ProductTypeElement("outcome", __GameOutcome.getTypeScriptAlgebraicType()),
+      new ProductTypeElement("wentFirst", AlgebraicType.createBoolType()),
       new ProductTypeElement("timestamp", AlgebraicType.createTimestampType()),
+      new ProductTypeElement("day", AlgebraicType.createU32Type()),
+      new ProductTypeElement("counted", AlgebraicType.createBoolType()),
     ]);
   }
 
@@ -9,33 +9,34 @@ import {
   AlgebraicValue,
   BinaryReader,
   BinaryWriter,
-  CallReducerFlags,
   ConnectionId,
   DbConnectionBuilder,
   DbConnectionImpl,
-  DbContext,
-  ErrorContextInterface,
-  Event,
-  EventContextInterface,
   Identity,
   ProductType,
   ProductTypeElement,
-  ReducerEventContextInterface,
   SubscriptionBuilderImpl,
-  SubscriptionEventContextInterface,
   SumType,
   SumTypeVariant,
   TableCache,
   TimeDuration,
   Timestamp,
   deepEqual,
+  type CallReducerFlags,
+  type DbContext,
+  type ErrorContextInterface,
+  type Event,
+  type EventContextInterface,
+  type ReducerEventContextInterface,
+  type SubscriptionEventContextInterface,
 } from "@clockworklabs/spacetimedb-sdk";
 import { Game } from "./game_type";
+import { GameSettings as __GameSettings } from "./game_settings_type";
 import { Winner as __Winner } from "./winner_type";
 import { Coord as __Coord } from "./coord_type";
-import { DroppedPiece as __DroppedPiece } from "./dropped_piece_type";
+import { GameBoard as __GameBoard } from "./game_board_type";
 
-import { EventContext, Reducer, RemoteReducers, RemoteTables } from ".";
+import { type EventContext, type Reducer, RemoteReducers, RemoteTables } from ".";
 
 /**
  * Table handle for the table `game`.
@@ -62,22 +63,22 @@ export class GameTableHandle {
     return this.tableCache.iter();
   }
   /**
-   * Access to the `room_id` unique index on the table `game`,
+   * Access to the `roomId` unique index on the table `game`,
    * which allows point queries on the field of the same name
    * via the [`GameRoomIdUnique.find`] method.
    *
    * Users are encouraged not to explicitly reference this type,
    * but to directly chain method calls,
-   * like `ctx.db.game.room_id().find(...)`.
+   * like `ctx.db.game.roomId().find(...)`.
    *
-   * Get a handle on the `room_id` unique index on the table `game`.
+   * Get a handle on the `roomId` unique index on the table `game`.
    */
-  room_id = {
-    // Find the subscribed row whose `room_id` column value is equal to `col_val`,
+  roomId = {
+    // Find the subscribed row whose `roomId` column value is equal to `col_val`,
     // if such a row is present in the client cache.
     find: (col_val: number): Game | undefined => {
       for (let row of this.tableCache.iter()) {
-        if (deepEqual(row.room_id, col_val)) {
+        if (deepEqual(row.roomId, col_val)) {
           return row;
         }
       }
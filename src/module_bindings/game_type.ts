@@ -9,36 +9,43 @@ import {
   AlgebraicValue,
   BinaryReader,
   BinaryWriter,
-  CallReducerFlags,
   ConnectionId,
   DbConnectionBuilder,
   DbConnectionImpl,
-  DbContext,
-  ErrorContextInterface,
-  Event,
-  EventContextInterface,
   Identity,
   ProductType,
   ProductTypeElement,
-  ReducerEventContextInterface,
   SubscriptionBuilderImpl,
-  SubscriptionEventContextInterface,
   SumType,
   SumTypeVariant,
   TableCache,
   TimeDuration,
   Timestamp,
   deepEqual,
+  type CallReducerFlags,
+  type DbContext,
+  type ErrorContextInterface,
+  type Event,
+  type EventContextInterface,
+  type ReducerEventContextInterface,
+  type SubscriptionEventContextInterface,
 } from "@clockworklabs/spacetimedb-sdk";
+import { GameSettings as __GameSettings } from "./game_settings_type";
 import { Winner as __Winner } from "./winner_type";
 import { Coord as __Coord } from "./coord_type";
-import { DroppedPiece as __DroppedPiece } from "./dropped_piece_type";
+import { GameBoard as __GameBoard } from "./game_board_type";
 
 export type Game = {
   roomId: number,
+  round: number,
+  settings: __GameSettings,
+  turnOrder: number[],
+  startingTeamId: number,
   winner: __Winner | undefined,
-  table: (__DroppedPiece | undefined)[][],
+  board: __GameBoard,
   latestMove: __Coord | undefined,
+  drawn: boolean,
+  positions: bigint[],
 };
 
 /**
@@ -52,9 +59,15 @@ export namespace Game {
   export function getTypeScriptAlgebraicType(): AlgebraicType {
     return AlgebraicType.createProductType([
       new ProductTypeElement("roomId", AlgebraicType.createU32Type()),
+      new ProductTypeElement("round", AlgebraicType.createU32Type()),
+      new ProductTypeElement("settings", __GameSettings.getTypeScriptAlgebraicType()),
+      new ProductTypeElement("turnOrder", AlgebraicType.createArrayType(AlgebraicType.createU32Type())),
+      new ProductTypeElement("startingTeamId", AlgebraicType.createU32Type()),
       new ProductTypeElement("winner", AlgebraicType.createOptionType(__Winner.getTypeScriptAlgebraicType())),
-      new ProductTypeElement("table", AlgebraicType.createArrayType(AlgebraicType.createArrayType(AlgebraicType.createOptionType(__DroppedPiece.getTypeScriptAlgebraicType())))),
+      new ProductTypeElement("board", __GameBoard.getTypeScriptAlgebraicType()),
       new ProductTypeElement("latestMove", AlgebraicType.createOptionType(__Coord.getTypeScriptAlgebraicType())),
+      new ProductTypeElement("drawn", AlgebraicType.createBoolType()),
+      new ProductTypeElement("positions", AlgebraicType.createArrayType(AlgebraicType.createU64Type())),
     ]);
   }
 
@@ -9,39 +9,59 @@ import {
   AlgebraicValue,
   BinaryReader,
   BinaryWriter,
-  CallReducerFlags,
   ConnectionId,
   DbConnectionBuilder,
   DbConnectionImpl,
-  DbContext,
-  ErrorContextInterface,
-  Event,
-  EventContextInterface,
   Identity,
   ProductType,
   ProductTypeElement,
-  ReducerEventContextInterface,
   SubscriptionBuilderImpl,
-  SubscriptionEventContextInterface,
   SumType,
   SumTypeVariant,
   TableCache,
   TimeDuration,
   Timestamp,
   deepEqual,
+  type CallReducerFlags,
+  type DbContext,
+  type ErrorContextInterface,
+  type Event,
+  type EventContextInterface,
+  type ReducerEventContextInterface,
+  type SubscriptionEventContextInterface,
 } from "@clockworklabs/spacetimedb-sdk";
 
 // Import and reexport all reducer arg types
+import { AcceptTakeback } from "./accept_takeback_reducer.ts";
+export { AcceptTakeback };
 import { AutoDeleteGameHistory } from "./auto_delete_game_history_reducer.ts";
 export { AutoDeleteGameHistory };
 import { AutoDeleteRoomIfAllOffline } from "./auto_delete_room_if_all_offline_reducer.ts";
 export { AutoDeleteRoomIfAllOffline };
+import { AutoJoinTeam } from "./auto_join_team_reducer.ts";
+export { AutoJoinTeam };
+import { BecomeSpectator } from "./become_spectator_reducer.ts";
+export { BecomeSpectator };
+import { CloseReplay } from "./close_replay_reducer.ts";
+export { CloseReplay };
 import { CreateGame } from "./create_game_reducer.ts";
 export { CreateGame };
 import { CreateRoom } from "./create_room_reducer.ts";
 export { CreateRoom };
+import { DecayRatings } from "./decay_ratings_reducer.ts";
+export { DecayRatings };
+import { DeclineTakeback } from "./decline_takeback_reducer.ts";
+export { DeclineTakeback };
 import { DropPiece } from "./drop_piece_reducer.ts";
 export { DropPiece };
+import { EndSeason } from "./end_season_reducer.ts";
+export { EndSeason };
+import { EquipSkin } from "./equip_skin_reducer.ts";
+export { EquipSkin };
+import { ExpireLeaderboards } from "./expire_leaderboards_reducer.ts";
+export { ExpireLeaderboards };
+import { ForfeitIfTeamOffline } from "./forfeit_if_team_offline_reducer.ts";
+export { ForfeitIfTeamOffline };
 import { Hello } from "./hello_reducer.ts";
 export { Hello };
 import { HelloWithText } from "./hello_with_text_reducer.ts";
@@ -56,136 +76,500 @@ import { JoinToTeam } from "./join_to_team_reducer.ts";
 export { JoinToTeam };
 import { LeaveRoom } from "./leave_room_reducer.ts";
 export { LeaveRoom };
-import { RestartGameHasWinner } from "./restart_game_has_winner_reducer.ts";
-export { RestartGameHasWinner };
-import { RestartGameTableFull } from "./restart_game_table_full_reducer.ts";
-export { RestartGameTableFull };
+import { OpenReplay } from "./open_replay_reducer.ts";
+export { OpenReplay };
+import { PopPiece } from "./pop_piece_reducer.ts";
+export { PopPiece };
+import { PurchaseSkin } from "./purchase_skin_reducer.ts";
+export { PurchaseSkin };
+import { RematchTimeout } from "./rematch_timeout_reducer.ts";
+export { RematchTimeout };
+import { RequestTakeback } from "./request_takeback_reducer.ts";
+export { RequestTakeback };
+import { Resign } from "./resign_reducer.ts";
+export { Resign };
 import { SendMessage } from "./send_message_reducer.ts";
 export { SendMessage };
+import { SendSpectatorMessage } from "./send_spectator_message_reducer.ts";
+export { SendSpectatorMessage };
 import { SetName } from "./set_name_reducer.ts";
 export { SetName };
+import { SetSpectatorJoin } from "./set_spectator_join_reducer.ts";
+export { SetSpectatorJoin };
+import { SetStartPolicy } from "./set_start_policy_reducer.ts";
+export { SetStartPolicy };
+import { SpectateRoom } from "./spectate_room_reducer.ts";
+export { SpectateRoom };
+import { StepReplay } from "./step_replay_reducer.ts";
+export { StepReplay };
+import { TurnTimeout } from "./turn_timeout_reducer.ts";
+export { TurnTimeout };
+import { VoteColumn } from "./vote_column_reducer.ts";
+export { VoteColumn };
+import { VoteRematch } from "./vote_rematch_reducer.ts";
+export { VoteRematch };
+import { VoteTimeout } from "./vote_timeout_reducer.ts";
+export { VoteTimeout };
 
 // Import and reexport all table handle types
+import { ArchivedGameTableHandle } from "./archived_game_table.ts";
+export { ArchivedGameTableHandle };
 import { AutoDeleteGameHistoryTimerTableHandle } from "./auto_delete_game_history_timer_table.ts";
 export { AutoDeleteGameHistoryTimerTableHandle };
 import { AutoDeleteRoomTimerTableHandle } from "./auto_delete_room_timer_table.ts";
 export { AutoDeleteRoomTimerTableHandle };
+import { CoinTransactionTableHandle } from "./coin_transaction_table.ts";
+export { CoinTransactionTableHandle };
+import { ForfeitTimerTableHandle } from "./forfeit_timer_table.ts";
+export { ForfeitTimerTableHandle };
 import { GameTableHandle } from "./game_table.ts";
 export { GameTableHandle };
 import { GameCurrentTeamTableHandle } from "./game_current_team_table.ts";
 export { GameCurrentTeamTableHandle };
 import { GameHistoryTableHandle } from "./game_history_table.ts";
 export { GameHistoryTableHandle };
+import { GameMoveTableHandle } from "./game_move_table.ts";
+export { GameMoveTableHandle };
+import { GameReplayTableHandle } from "./game_replay_table.ts";
+export { GameReplayTableHandle };
 import { JoinRoomTableHandle } from "./join_room_table.ts";
 export { JoinRoomTableHandle };
 import { JoinTeamTableHandle } from "./join_team_table.ts";
 export { JoinTeamTableHandle };
+import { LeaderboardEntryTableHandle } from "./leaderboard_entry_table.ts";
+export { LeaderboardEntryTableHandle };
+import { LeaderboardExpiryTableHandle } from "./leaderboard_expiry_table.ts";
+export { LeaderboardExpiryTableHandle };
+import { LeaderboardExpiryTimerTableHandle } from "./leaderboard_expiry_timer_table.ts";
+export { LeaderboardExpiryTimerTableHandle };
 import { MessageTableHandle } from "./message_table.ts";
 export { MessageTableHandle };
+import { OwnedSkinTableHandle } from "./owned_skin_table.ts";
+export { OwnedSkinTableHandle };
 import { PlayerTableHandle } from "./player_table.ts";
 export { PlayerTableHandle };
+import { PlayerRatingTableHandle } from "./player_rating_table.ts";
+export { PlayerRatingTableHandle };
+import { RatingDecayTimerTableHandle } from "./rating_decay_timer_table.ts";
+export { RatingDecayTimerTableHandle };
+import { RematchTimerTableHandle } from "./rematch_timer_table.ts";
+export { RematchTimerTableHandle };
+import { RematchVoteTableHandle } from "./rematch_vote_table.ts";
+export { RematchVoteTableHandle };
 import { RoomTableHandle } from "./room_table.ts";
 export { RoomTableHandle };
-import { StatsOneMonthTableHandle } from "./stats_one_month_table.ts";
-export { StatsOneMonthTableHandle };
+import { SeasonTableHandle } from "./season_table.ts";
+export { SeasonTableHandle };
+import { SeasonTimerTableHandle } from "./season_timer_table.ts";
+export { SeasonTimerTableHandle };
+import { SkinTableHandle } from "./skin_table.ts";
+export { SkinTableHandle };
+import { TakebackRequestTableHandle } from "./takeback_request_table.ts";
+export { TakebackRequestTableHandle };
 import { TeamTableHandle } from "./team_table.ts";
 export { TeamTableHandle };
+import { TeamVoteTableHandle } from "./team_vote_table.ts";
+export { TeamVoteTableHandle };
+import { TurnTimerTableHandle } from "./turn_timer_table.ts";
+export { TurnTimerTableHandle };
+import { VoteTimerTableHandle } from "./vote_timer_table.ts";
+export { VoteTimerTableHandle };
+import { WalletTableHandle } from "./wallet_table.ts";
+export { WalletTableHandle };
 
 // Import and reexport all types
+import { ArchivedGame } from "./archived_game_type.ts";
+export { ArchivedGame };
+import { ArchivedMove } from "./archived_move_type.ts";
+export { ArchivedMove };
+import { ArchivedTeam } from "./archived_team_type.ts";
+export { ArchivedTeam };
 import { AutoDeleteGameHistoryTimer } from "./auto_delete_game_history_timer_type.ts";
 export { AutoDeleteGameHistoryTimer };
 import { AutoDeleteRoomTimer } from "./auto_delete_room_timer_type.ts";
 export { AutoDeleteRoomTimer };
+import { BotDifficulty } from "./bot_difficulty_type.ts";
+export { BotDifficulty };
+import { CoinReason } from "./coin_reason_type.ts";
+export { CoinReason };
+import { CoinTransaction } from "./coin_transaction_type.ts";
+export { CoinTransaction };
 import { Coord } from "./coord_type.ts";
 export { Coord };
-import { DroppedPiece } from "./dropped_piece_type.ts";
-export { DroppedPiece };
+import { DropMode } from "./drop_mode_type.ts";
+export { DropMode };
+import { ForfeitTimer } from "./forfeit_timer_type.ts";
+export { ForfeitTimer };
 import { Game } from "./game_type.ts";
 export { Game };
+import { GameBoard } from "./game_board_type.ts";
+export { GameBoard };
 import { GameCurrentTeam } from "./game_current_team_type.ts";
 export { GameCurrentTeam };
 import { GameHistory } from "./game_history_type.ts";
 export { GameHistory };
+import { GameMove } from "./game_move_type.ts";
+export { GameMove };
+import { GameOutcome } from "./game_outcome_type.ts";
+export { GameOutcome };
+import { GameReplay } from "./game_replay_type.ts";
+export { GameReplay };
+import { GameSettings } from "./game_settings_type.ts";
+export { GameSettings };
 import { JoinRoom } from "./join_room_type.ts";
 export { JoinRoom };
 import { JoinTeam } from "./join_team_type.ts";
 export { JoinTeam };
+import { Leaderboard } from "./leaderboard_type.ts";
+export { Leaderboard };
+import { LeaderboardEntry } from "./leaderboard_entry_type.ts";
+export { LeaderboardEntry };
+import { LeaderboardExpiry } from "./leaderboard_expiry_type.ts";
+export { LeaderboardExpiry };
+import { LeaderboardExpiryTimer } from "./leaderboard_expiry_timer_type.ts";
+export { LeaderboardExpiryTimer };
 import { Message } from "./message_type.ts";
 export { Message };
+import { MoveKind } from "./move_kind_type.ts";
+export { MoveKind };
+import { OwnedSkin } from "./owned_skin_type.ts";
+export { OwnedSkin };
 import { Player } from "./player_type.ts";
 export { Player };
+import { PlayerRating } from "./player_rating_type.ts";
+export { PlayerRating };
+import { Rarity } from "./rarity_type.ts";
+export { Rarity };
+import { RatingDecayTimer } from "./rating_decay_timer_type.ts";
+export { RatingDecayTimer };
+import { RematchTimer } from "./rematch_timer_type.ts";
+export { RematchTimer };
+import { RematchVote } from "./rematch_vote_type.ts";
+export { RematchVote };
 import { Room } from "./room_type.ts";
 export { Room };
-import { StatsOneMonth } from "./stats_one_month_type.ts";
-export { StatsOneMonth };
+import { Season } from "./season_type.ts";
+export { Season };
+import { SeasonTimer } from "./season_timer_type.ts";
+export { SeasonTimer };
+import { Skin } from "./skin_type.ts";
+export { Skin };
+import { SpectatorJoin } from "./spectator_join_type.ts";
+export { SpectatorJoin };
+import { StartPolicy } from "./start_policy_type.ts";
+export { StartPolicy };
+import { TakebackRequest } from "./takeback_request_type.ts";
+export { TakebackRequest };
 import { Team } from "./team_type.ts";
 export { Team };
+import { TeamMask } from "./team_mask_type.ts";
+export { TeamMask };
+import { TeamVote } from "./team_vote_type.ts";
+export { TeamVote };
+import { TimeoutAction } from "./timeout_action_type.ts";
+export { TimeoutAction };
+import { TurnTimer } from "./turn_timer_type.ts";
+export { TurnTimer };
+import { Variant } from "./variant_type.ts";
+export { Variant };
+import { VoteTimer } from "./vote_timer_type.ts";
+export { VoteTimer };
+import { Wallet } from "./wallet_type.ts";
+export { Wallet };
 import { Winner } from "./winner_type.ts";
 export { Winner };
 
 const REMOTE_MODULE = {
   tables: {
+    archived_game: {
+      tableName: "archived_game",
+      rowType: ArchivedGame.getTypeScriptAlgebraicType(),
+      primaryKey: "id",
+      primaryKeyInfo: {
+        colName: "id",
+        colType: ArchivedGame.getTypeScriptAlgebraicType().product.elements[0].algebraicType,
+      },
+    },
     auto_delete_game_history_timer: {
       tableName: "auto_delete_game_history_timer",
       rowType: AutoDeleteGameHistoryTimer.getTypeScriptAlgebraicType(),
       primaryKey: "scheduledId",
+      primaryKeyInfo: {
+        colName: "scheduledId",
+        colType: AutoDeleteGameHistoryTimer.getTypeScriptAlgebraicType().product.elements[0].algebraicType,
+      },
     },
     auto_delete_room_timer: {
       tableName: "auto_delete_room_timer",
       rowType: AutoDeleteRoomTimer.getTypeScriptAlgebraicType(),
       primaryKey: "scheduledId",
+      primaryKeyInfo: {
+        colName: "scheduledId",
+        colType: AutoDeleteRoomTimer.getTypeScriptAlgebraicType().product.elements[0].algebraicType,
+      },
+    },
+    coin_transaction: {
+      tableName: "coin_transaction",
+      rowType: CoinTransaction.getTypeScriptAlgebraicType(),
+      primaryKey: "id",
+      primaryKeyInfo: {
+        colName: "id",
+        colType: CoinTransaction.getTypeScriptAlgebraicType().product.elements[0].algebraicType,
+      },
+    },
+    forfeit_timer: {
+      tableName: "forfeit_timer",
+      rowType: ForfeitTimer.getTypeScriptAlgebraicType(),
+      primaryKey: "scheduledId",
+      primaryKeyInfo: {
+        colName: "scheduledId",
+        colType: ForfeitTimer.getTypeScriptAlgebraicType().product.elements[0].algebraicType,
+      },
     },
     game: {
       tableName: "game",
       rowType: Game.getTypeScriptAlgebraicType(),
       primaryKey: "roomId",
+      primaryKeyInfo: {
+        colName: "roomId",
+        colType: Game.getTypeScriptAlgebraicType().product.elements[0].algebraicType,
+      },
     },
     game_current_team: {
       tableName: "game_current_team",
       rowType: GameCurrentTeam.getTypeScriptAlgebraicType(),
       primaryKey: "gameId",
+      primaryKeyInfo: {
+        colName: "gameId",
+        colType: GameCurrentTeam.getTypeScriptAlgebraicType().product.elements[0].algebraicType,
+      },
     },
     game_history: {
       tableName: "game_history",
       rowType: GameHistory.getTypeScriptAlgebraicType(),
       primaryKey: "id",
+      primaryKeyInfo: {
+        colName: "id",
+        colType: GameHistory.getTypeScriptAlgebraicType().product.elements[0].algebraicType,
+      },
+    },
+    game_move: {
+      tableName: "game_move",
+      rowType: GameMove.getTypeScriptAlgebraicType(),
+      primaryKey: "id",
+      primaryKeyInfo: {
+        colName: "id",
+        colType: GameMove.getTypeScriptAlgebraicType().product.elements[0].algebraicType,
+      },
+    },
+    game_replay: {
+      tableName: "game_replay",
+      rowType: GameReplay.getTypeScriptAlgebraicType(),
+      primaryKey: "viewer",
+      primaryKeyInfo: {
+        colName: "viewer",
+        colType: GameReplay.getTypeScriptAlgebraicType().product.elements[0].algebraicType,
+      },
     },
     join_room: {
       tableName: "join_room",
       rowType: JoinRoom.getTypeScriptAlgebraicType(),
       primaryKey: "joiner",
+      primaryKeyInfo: {
+        colName: "joiner",
+        colType: JoinRoom.getTypeScriptAlgebraicType().product.elements[1].algebraicType,
+      },
     },
     join_team: {
       tableName: "join_team",
       rowType: JoinTeam.getTypeScriptAlgebraicType(),
       primaryKey: "joiner",
+      primaryKeyInfo: {
+        colName: "joiner",
+        colType: JoinTeam.getTypeScriptAlgebraicType().product.elements[1].algebraicType,
+      },
+    },
+    leaderboard_entry: {
+      tableName: "leaderboard_entry",
+      rowType: LeaderboardEntry.getTypeScriptAlgebraicType(),
+      primaryKey: "id",
+      primaryKeyInfo: {
+        colName: "id",
+        colType: LeaderboardEntry.getTypeScriptAlgebraicType().product.elements[0].algebraicType,
+      },
+    },
+    leaderboard_expiry: {
+      tableName: "leaderboard_expiry",
+      rowType: LeaderboardExpiry.getTypeScriptAlgebraicType(),
+      primaryKey: "board",
+      primaryKeyInfo: {
+        colName: "board",
+        colType: LeaderboardExpiry.getTypeScriptAlgebraicType().product.elements[0].algebraicType,
+      },
+    },
+    leaderboard_expiry_timer: {
+      tableName: "leaderboard_expiry_timer",
+      rowType: LeaderboardExpiryTimer.getTypeScriptAlgebraicType(),
+      primaryKey: "scheduledId",
+      primaryKeyInfo: {
+        colName: "scheduledId",
+        colType: LeaderboardExpiryTimer.getTypeScriptAlgebraicType().product.elements[0].algebraicType,
+      },
     },
     message: {
       tableName: "message",
       rowType: Message.getTypeScriptAlgebraicType(),
     },
+    owned_skin: {
+      tableName: "owned_skin",
+      rowType: OwnedSkin.getTypeScriptAlgebraicType(),
+      primaryKey: "id",
+      primaryKeyInfo: {
+        colName: "id",
+        colType: OwnedSkin.getTypeScriptAlgebraicType().product.elements[0].algebraicType,
+      },
+    },
     player: {
       tableName: "player",
       rowType: Player.getTypeScriptAlgebraicType(),
       primaryKey: "identity",
+      primaryKeyInfo: {
+        colName: "identity",
+        colType: Player.getTypeScriptAlgebraicType().product.elements[0].algebraicType,
+      },
+    },
+    player_rating: {
+      tableName: "player_rating",
+      rowType: PlayerRating.getTypeScriptAlgebraicType(),
+      primaryKey: "player",
+      primaryKeyInfo: {
+        colName: "player",
+        colType: PlayerRating.getTypeScriptAlgebraicType().product.elements[0].algebraicType,
+      },
+    },
+    rating_decay_timer: {
+      tableName: "rating_decay_timer",
+      rowType: RatingDecayTimer.getTypeScriptAlgebraicType(),
+      primaryKey: "scheduledId",
+      primaryKeyInfo: {
+        colName: "scheduledId",
+        colType: RatingDecayTimer.getTypeScriptAlgebraicType().product.elements[0].algebraicType,
+      },
+    },
+    rematch_timer: {
+      tableName: "rematch_timer",
+      rowType: RematchTimer.getTypeScriptAlgebraicType(),
+      primaryKey: "scheduledId",
+      primaryKeyInfo: {
+        colName: "scheduledId",
+        colType: RematchTimer.getTypeScriptAlgebraicType().product.elements[0].algebraicType,
+      },
+    },
+    rematch_vote: {
+      tableName: "rematch_vote",
+      rowType: RematchVote.getTypeScriptAlgebraicType(),
+      primaryKey: "voter",
+      primaryKeyInfo: {
+        colName: "voter",
+        colType: RematchVote.getTypeScriptAlgebraicType().product.elements[0].algebraicType,
+      },
     },
     room: {
       tableName: "room",
       rowType: Room.getTypeScriptAlgebraicType(),
       primaryKey: "id",
+      primaryKeyInfo: {
+        colName: "id",
+        colType: Room.getTypeScriptAlgebraicType().product.elements[0].algebraicType,
+      },
     },
-    stats_one_month: {
-      tableName: "stats_one_month",
-      rowType: StatsOneMonth.getTypeScriptAlgebraicType(),
-      primaryKey: "player",
+    season: {
+      tableName: "season",
+      rowType: Season.getTypeScriptAlgebraicType(),
+      primaryKey: "id",
+      primaryKeyInfo: {
+        colName: "id",
+        colType: Season.getTypeScriptAlgebraicType().product.elements[0].algebraicType,
+      },
+    },
+    season_timer: {
+      tableName: "season_timer",
+      rowType: SeasonTimer.getTypeScriptAlgebraicType(),
+      primaryKey: "scheduledId",
+      primaryKeyInfo: {
+        colName: "scheduledId",
+        colType: SeasonTimer.getTypeScriptAlgebraicType().product.elements[0].algebraicType,
+      },
+    },
+    skin: {
+      tableName: "skin",
+      rowType: Skin.getTypeScriptAlgebraicType(),
+      primaryKey: "id",
+      primaryKeyInfo: {
+        colName: "id",
+        colType: Skin.getTypeScriptAlgebraicType().product.elements[0].algebraicType,
+      },
+    },
+    takeback_request: {
+      tableName: "takeback_request",
+      rowType: TakebackRequest.getTypeScriptAlgebraicType(),
+      primaryKey: "gameId",
+      primaryKeyInfo: {
+        colName: "gameId",
+        colType: TakebackRequest.getTypeScriptAlgebraicType().product.elements[0].algebraicType,
+      },
     },
     team: {
       tableName: "team",
       rowType: Team.getTypeScriptAlgebraicType(),
       primaryKey: "id",
+      primaryKeyInfo: {
+        colName: "id",
+        colType: Team.getTypeScriptAlgebraicType().product.elements[0].algebraicType,
+      },
+    },
+    team_vote: {
+      tableName: "team_vote",
+      rowType: TeamVote.getTypeScriptAlgebraicType(),
+      primaryKey: "voter",
+      primaryKeyInfo: {
+        colName: "voter",
+        colType: TeamVote.getTypeScriptAlgebraicType().product.elements[0].algebraicType,
+      },
+    },
+    turn_timer: {
+      tableName: "turn_timer",
+      rowType: TurnTimer.getTypeScriptAlgebraicType(),
+      primaryKey: "scheduledId",
+      primaryKeyInfo: {
+        colName: "scheduledId",
+        colType: TurnTimer.getTypeScriptAlgebraicType().product.elements[0].algebraicType,
+      },
+    },
+    vote_timer: {
+      tableName: "vote_timer",
+      rowType: VoteTimer.getTypeScriptAlgebraicType(),
+      primaryKey: "scheduledId",
+      primaryKeyInfo: {
+        colName: "scheduledId",
+        colType: VoteTimer.getTypeScriptAlgebraicType().product.elements[0].algebraicType,
+      },
+    },
+    wallet: {
+      tableName: "wallet",
+      rowType: Wallet.getTypeScriptAlgebraicType(),
+      primaryKey: "owner",
+      primaryKeyInfo: {
+        colName: "owner",
+        colType: Wallet.getTypeScriptAlgebraicType().product.elements[0].algebraicType,
+      },
     },
   },
   reducers: {
+    accept_takeback: {
+      reducerName: "accept_takeback",
+      argsType: AcceptTakeback.getTypeScriptAlgebraicType(),
+    },
     auto_delete_game_history: {
       reducerName: "auto_delete_game_history",
       argsType: AutoDeleteGameHistory.getTypeScriptAlgebraicType(),
@@ -194,6 +578,18 @@ const REMOTE_MODULE = {
       reducerName: "auto_delete_room_if_all_offline",
       argsType: AutoDeleteRoomIfAllOffline.getTypeScriptAlgebraicType(),
     },
+    auto_join_team: {
+      reducerName: "auto_join_team",
+      argsType: AutoJoinTeam.getTypeScriptAlgebraicType(),
+    },
+    become_spectator: {
+      reducerName: "become_spectator",
+      argsType: BecomeSpectator.getTypeScriptAlgebraicType(),
+    },
+    close_replay: {
+      reducerName: "close_replay",
+      argsType: CloseReplay.getTypeScriptAlgebraicType(),
+    },
     create_game: {
       reducerName: "create_game",
       argsType: CreateGame.getTypeScriptAlgebraicType(),
@@ -202,10 +598,34 @@ const REMOTE_MODULE = {
       reducerName: "create_room",
       argsType: CreateRoom.getTypeScriptAlgebraicType(),
     },
+    decay_ratings: {
+      reducerName: "decay_ratings",
+      argsType: DecayRatings.getTypeScriptAlgebraicType(),
+    },
+    decline_takeback: {
+      reducerName: "decline_takeback",
+      argsType: DeclineTakeback.getTypeScriptAlgebraicType(),
+    },
     drop_piece: {
       reducerName: "drop_piece",
       argsType: DropPiece.getTypeScriptAlgebraicType(),
     },
+    end_season: {
+      reducerName: "end_season",
+      argsType: EndSeason.getTypeScriptAlgebraicType(),
+    },
+    equip_skin: {
+      reducerName: "equip_skin",
+      argsType: EquipSkin.getTypeScriptAlgebraicType(),
+    },
+    expire_leaderboards: {
+      reducerName: "expire_leaderboards",
+      argsType: ExpireLeaderboards.getTypeScriptAlgebraicType(),
+    },
+    forfeit_if_team_offline: {
+      reducerName: "forfeit_if_team_offline",
+      argsType: ForfeitIfTeamOffline.getTypeScriptAlgebraicType(),
+    },
     hello: {
       reducerName: "hello",
       argsType: Hello.getTypeScriptAlgebraicType(),
@@ -234,22 +654,77 @@ const REMOTE_MODULE = {
       reducerName: "leave_room",
       argsType: LeaveRoom.getTypeScriptAlgebraicType(),
     },
-    restart_game_has_winner: {
-      reducerName: "restart_game_has_winner",
-      argsType: RestartGameHasWinner.getTypeScriptAlgebraicType(),
+    open_replay: {
+      reducerName: "open_replay",
+      argsType: OpenReplay.getTypeScriptAlgebraicType(),
+    },
+    pop_piece: {
+      reducerName: "pop_piece",
+      argsType: PopPiece.getTypeScriptAlgebraicType(),
     },
-    restart_game_table_full: {
-      reducerName: "restart_game_table_full",
-      argsType: RestartGameTableFull.getTypeScriptAlgebraicType(),
+    purchase_skin: {
+      reducerName: "purchase_skin",
+      argsType: PurchaseSkin.getTypeScriptAlgebraicType(),
+    },
+    rematch_timeout: {
+      reducerName: "rematch_timeout",
+      argsType: RematchTimeout.getTypeScriptAlgebraicType(),
+    },
+    request_takeback: {
+      reducerName: "request_takeback",
+      argsType: RequestTakeback.getTypeScriptAlgebraicType(),
+    },
+    resign: {
+      reducerName: "resign",
+      argsType: Resign.getTypeScriptAlgebraicType(),
     },
     send_message: {
       reducerName: "send_message",
       argsType: SendMessage.getTypeScriptAlgebraicType(),
     },
+    send_spectator_message: {
+      reducerName: "send_spectator_message",
+      argsType: SendSpectatorMessage.getTypeScriptAlgebraicType(),
+    },
     set_name: {
       reducerName: "set_name",
       argsType: SetName.getTypeScriptAlgebraicType(),
     },
+    set_spectator_join: {
+      reducerName: "set_spectator_join",
+      argsType: SetSpectatorJoin.getTypeScriptAlgebraicType(),
+    },
+    set_start_policy: {
+      reducerName: "set_start_policy",
+      argsType: SetStartPolicy.getTypeScriptAlgebraicType(),
+    },
+    spectate_room: {
+      reducerName: "spectate_room",
+      argsType: SpectateRoom.getTypeScriptAlgebraicType(),
+    },
+    step_replay: {
+      reducerName: "step_replay",
+      argsType: StepReplay.getTypeScriptAlgebraicType(),
+    },
+    turn_timeout: {
+      reducerName: "turn_timeout",
+      argsType: TurnTimeout.getTypeScriptAlgebraicType(),
+    },
+    vote_column: {
+      reducerName: "vote_column",
+      argsType: VoteColumn.getTypeScriptAlgebraicType(),
+    },
+    vote_rematch: {
+      reducerName: "vote_rematch",
+      argsType: VoteRematch.getTypeScriptAlgebraicType(),
+    },
+    vote_timeout: {
+      reducerName: "vote_timeout",
+      argsType: VoteTimeout.getTypeScriptAlgebraicType(),
+    },
+  },
+  versionInfo: {
+    cliVersion: "1.2.0",
   },
   // Constructors which are used by the DbConnectionImpl to
   // extract type information from the generated RemoteModule.
@@ -277,11 +752,21 @@ const REMOTE_MODULE = {
 
 // A type representing all the possible variants of a reducer.
 export type Reducer = never
+| { name: "AcceptTakeback", args: AcceptTakeback }
 | { name: "AutoDeleteGameHistory", args: AutoDeleteGameHistory }
 | { name: "AutoDeleteRoomIfAllOffline", args: AutoDeleteRoomIfAllOffline }
+| { name: "AutoJoinTeam", args: AutoJoinTeam }
+| { name: "BecomeSpectator", args: BecomeSpectator }
+| { name: "CloseReplay", args: CloseReplay }
 | { name: "CreateGame", args: CreateGame }
 | { name: "CreateRoom", args: CreateRoom }
+| { name: "DecayRatings", args: DecayRatings }
+| { name: "DeclineTakeback", args: DeclineTakeback }
 | { name: "DropPiece", args: DropPiece }
+| { name: "EndSeason", args: EndSeason }
+| { name: "EquipSkin", args: EquipSkin }
+| { name: "ExpireLeaderboards", args: ExpireLeaderboards }
+| { name: "ForfeitIfTeamOffline", args: ForfeitIfTeamOffline }
 | { name: "Hello", args: Hello }
 | { name: "HelloWithText", args: HelloWithText }
 | { name: "IdentityConnected", args: IdentityConnected }
@@ -289,15 +774,40 @@ export type Reducer = never
 | { name: "JoinToRoom", args: JoinToRoom }
 | { name: "JoinToTeam", args: JoinToTeam }
 | { name: "LeaveRoom", args: LeaveRoom }
-| { name: "RestartGameHasWinner", args: RestartGameHasWinner }
-| { name: "RestartGameTableFull", args: RestartGameTableFull }
+| { name: "OpenReplay", args: OpenReplay }
+| { name: "PopPiece", args: PopPiece }
+| { name: "PurchaseSkin", args: PurchaseSkin }
+| { name: "RematchTimeout", args: RematchTimeout }
+| { name: "RequestTakeback", args: RequestTakeback }
+| { name: "Resign", args: Resign }
 | { name: "SendMessage", args: SendMessage }
+| { name: "SendSpectatorMessage", args: SendSpectatorMessage }
 | { name: "SetName", args: SetName }
+| { name: "SetSpectatorJoin", args: SetSpectatorJoin }
+| { name: "SetStartPolicy", args: SetStartPolicy }
+| { name: "SpectateRoom", args: SpectateRoom }
+| { name: "StepReplay", args: StepReplay }
+| { name: "TurnTimeout", args: TurnTimeout }
+| { name: "VoteColumn", args: VoteColumn }
+| { name: "VoteRematch", args: VoteRematch }
+| { name: "VoteTimeout", args: VoteTimeout }
 ;
 
 export class RemoteReducers {
   constructor(private connection: DbConnectionImpl, private setCallReducerFlags: SetReducerFlags) {}
 
+  acceptTakeback() {
+    this.connection.callReducer("accept_takeback", new Uint8Array(0), this.setCallReducerFlags.acceptTakebackFlags);
+  }
+
+  onAcceptTakeback(callback: (ctx: ReducerEventContext) => void) {
+    this.connection.onReducer("accept_takeback", callback);
+  }
+
+  removeOnAcceptTakeback(callback: (ctx: ReducerEventContext) => void) {
+    this.connection.offReducer("accept_takeback", callback);
+  }
+
   autoDeleteGameHistory(timer: AutoDeleteGameHistoryTimer) {
     const __args = { timer };
     let __writer = new BinaryWriter(1024);
@@ -330,15 +840,55 @@ export class RemoteReducers {
     this.connection.offReducer("auto_delete_room_if_all_offline", callback);
   }
 
-  createGame() {
-    this.connection.callReducer("create_game", new Uint8Array(0), this.setCallReducerFlags.createGameFlags);
+  autoJoinTeam() {
+    this.connection.callReducer("auto_join_team", new Uint8Array(0), this.setCallReducerFlags.autoJoinTeamFlags);
+  }
+
+  onAutoJoinTeam(callback: (ctx: ReducerEventContext) => void) {
+    this.connection.onReducer("auto_join_team", callback);
+  }
+
+  removeOnAutoJoinTeam(callback: (ctx: ReducerEventContext) => void) {
+    this.connection.offReducer("auto_join_team", callback);
+  }
+
+  becomeSpectator() {
+    this.connection.callReducer("become_spectator", new Uint8Array(0), this.setCallReducerFlags.becomeSpectatorFlags);
+  }
+
+  onBecomeSpectator(callback: (ctx: ReducerEventContext) => void) {
+    this.connection.onReducer("become_spectator", callback);
+  }
+
+  removeOnBecomeSpectator(callback: (ctx: ReducerEventContext) => void) {
+    this.connection.offReducer("become_spectator", callback);
+  }
+
+  closeReplay() {
+    this.connection.callReducer("close_replay", new Uint8Array(0), this.setCallReducerFlags.closeReplayFlags);
+  }
+
+  onCloseReplay(callback: (ctx: ReducerEventContext) => void) {
+    this.connection.onReducer("close_replay", callback);
+  }
+
+  removeOnCloseReplay(callback: (ctx: ReducerEventContext) => void) {
+    this.connection.offReducer("close_replay", callback);
   }
 
-  onCreateGame(callback: (ctx: ReducerEventContext) => void) {
+  createGame(settings: GameSettings) {
+    const __args = { settings };
+    let __writer = new BinaryWriter(1024);
+    CreateGame.getTypeScriptAlgebraicType().serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("create_game", __argsBuffer, this.setCallReducerFlags.createGameFlags);
+  }
+
+  onCreateGame(callback: (ctx: ReducerEventContext, settings: GameSettings) => void) {
     this.connection.onReducer("create_game", callback);
   }
 
-  removeOnCreateGame(callback: (ctx: ReducerEventContext) => void) {
+  removeOnCreateGame(callback: (ctx: ReducerEventContext, settings: GameSettings) => void) {
     this.connection.offReducer("create_game", callback);
   }
 
@@ -358,6 +908,34 @@ export class RemoteReducers {
     this.connection.offReducer("create_room", callback);
   }
 
+  decayRatings(timer: RatingDecayTimer) {
+    const __args = { timer };
+    let __writer = new BinaryWriter(1024);
+    DecayRatings.getTypeScriptAlgebraicType().serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("decay_ratings", __argsBuffer, this.setCallReducerFlags.decayRatingsFlags);
+  }
+
+  onDecayRatings(callback: (ctx: ReducerEventContext, timer: RatingDecayTimer) => void) {
+    this.connection.onReducer("decay_ratings", callback);
+  }
+
+  removeOnDecayRatings(callback: (ctx: ReducerEventContext, timer: RatingDecayTimer) => void) {
+    this.connection.offReducer("decay_ratings", callback);
+  }
+
+  declineTakeback() {
+    this.connection.callReducer("decline_takeback", new Uint8Array(0), this.setCallReducerFlags.declineTakebackFlags);
+  }
+
+  onDeclineTakeback(callback: (ctx: ReducerEventContext) => void) {
+    this.connection.onReducer("decline_takeback", callback);
+  }
+
+  removeOnDeclineTakeback(callback: (ctx: ReducerEventContext) => void) {
+    this.connection.offReducer("decline_takeback", callback);
+  }
+
   dropPiece(column: number) {
     const __args = { column };
     let __writer = new BinaryWriter(1024);
@@ -374,6 +952,70 @@ export class RemoteReducers {
     this.connection.offReducer("drop_piece", callback);
   }
 
+  endSeason(timer: SeasonTimer) {
+    const __args = { timer };
+    let __writer = new BinaryWriter(1024);
+    EndSeason.getTypeScriptAlgebraicType().serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("end_season", __argsBuffer, this.setCallReducerFlags.endSeasonFlags);
+  }
+
+  onEndSeason(callback: (ctx: ReducerEventContext, timer: SeasonTimer) => void) {
+    this.connection.onReducer("end_season", callback);
+  }
+
+  removeOnEndSeason(callback: (ctx: ReducerEventContext, timer: SeasonTimer) => void) {
+    this.connection.offReducer("end_season", callback);
+  }
+
+  equipSkin(skinId: number | undefined) {
+    const __args = { skinId };
+    let __writer = new BinaryWriter(1024);
+    EquipSkin.getTypeScriptAlgebraicType().serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("equip_skin", __argsBuffer, this.setCallReducerFlags.equipSkinFlags);
+  }
+
+  onEquipSkin(callback: (ctx: ReducerEventContext, skinId: number | undefined) => void) {
+    this.connection.onReducer("equip_skin", callback);
+  }
+
+  removeOnEquipSkin(callback: (ctx: ReducerEventContext, skinId: number | undefined) => void) {
+    this.connection.offReducer("equip_skin", callback);
+  }
+
+  expireLeaderboards(timer: LeaderboardExpiryTimer) {
+    const __args = { timer };
+    let __writer = new BinaryWriter(1024);
+    ExpireLeaderboards.getTypeScriptAlgebraicType().serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("expire_leaderboards", __argsBuffer, this.setCallReducerFlags.expireLeaderboardsFlags);
+  }
+
+  onExpireLeaderboards(callback: (ctx: ReducerEventContext, timer: LeaderboardExpiryTimer) => void) {
+    this.connection.onReducer("expire_leaderboards", callback);
+  }
+
+  removeOnExpireLeaderboards(callback: (ctx: ReducerEventContext, timer: LeaderboardExpiryTimer) => void) {
+    this.connection.offReducer("expire_leaderboards", callback);
+  }
+
+  forfeitIfTeamOffline(timer: ForfeitTimer) {
+    const __args = { timer };
+    let __writer = new BinaryWriter(1024);
+    ForfeitIfTeamOffline.getTypeScriptAlgebraicType().serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("forfeit_if_team_offline", __argsBuffer, this.setCallReducerFlags.forfeitIfTeamOfflineFlags);
+  }
+
+  onForfeitIfTeamOffline(callback: (ctx: ReducerEventContext, timer: ForfeitTimer) => void) {
+    this.connection.onReducer("forfeit_if_team_offline", callback);
+  }
+
+  removeOnForfeitIfTeamOffline(callback: (ctx: ReducerEventContext, timer: ForfeitTimer) => void) {
+    this.connection.offReducer("forfeit_if_team_offline", callback);
+  }
+
   hello() {
     this.connection.callReducer("hello", new Uint8Array(0), this.setCallReducerFlags.helloFlags);
   }
@@ -462,28 +1104,92 @@ export class RemoteReducers {
     this.connection.offReducer("leave_room", callback);
   }
 
-  restartGameHasWinner() {
-    this.connection.callReducer("restart_game_has_winner", new Uint8Array(0), this.setCallReducerFlags.restartGameHasWinnerFlags);
+  openReplay(archiveId: number) {
+    const __args = { archiveId };
+    let __writer = new BinaryWriter(1024);
+    OpenReplay.getTypeScriptAlgebraicType().serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("open_replay", __argsBuffer, this.setCallReducerFlags.openReplayFlags);
+  }
+
+  onOpenReplay(callback: (ctx: ReducerEventContext, archiveId: number) => void) {
+    this.connection.onReducer("open_replay", callback);
+  }
+
+  removeOnOpenReplay(callback: (ctx: ReducerEventContext, archiveId: number) => void) {
+    this.connection.offReducer("open_replay", callback);
+  }
+
+  popPiece(column: number) {
+    const __args = { column };
+    let __writer = new BinaryWriter(1024);
+    PopPiece.getTypeScriptAlgebraicType().serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("pop_piece", __argsBuffer, this.setCallReducerFlags.popPieceFlags);
+  }
+
+  onPopPiece(callback: (ctx: ReducerEventContext, column: number) => void) {
+    this.connection.onReducer("pop_piece", callback);
+  }
+
+  removeOnPopPiece(callback: (ctx: ReducerEventContext, column: number) => void) {
+    this.connection.offReducer("pop_piece", callback);
+  }
+
+  purchaseSkin(skinId: number) {
+    const __args = { skinId };
+    let __writer = new BinaryWriter(1024);
+    PurchaseSkin.getTypeScriptAlgebraicType().serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("purchase_skin", __argsBuffer, this.setCallReducerFlags.purchaseSkinFlags);
+  }
+
+  onPurchaseSkin(callback: (ctx: ReducerEventContext, skinId: number) => void) {
+    this.connection.onReducer("purchase_skin", callback);
+  }
+
+  removeOnPurchaseSkin(callback: (ctx: ReducerEventContext, skinId: number) => void) {
+    this.connection.offReducer("purchase_skin", callback);
+  }
+
+  rematchTimeout(timer: RematchTimer) {
+    const __args = { timer };
+    let __writer = new BinaryWriter(1024);
+    RematchTimeout.getTypeScriptAlgebraicType().serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("rematch_timeout", __argsBuffer, this.setCallReducerFlags.rematchTimeoutFlags);
+  }
+
+  onRematchTimeout(callback: (ctx: ReducerEventContext, timer: RematchTimer) => void) {
+    this.connection.onReducer("rematch_timeout", callback);
+  }
+
+  removeOnRematchTimeout(callback: (ctx: ReducerEventContext, timer: RematchTimer) => void) {
+    this.connection.offReducer("rematch_timeout", callback);
   }
 
-  onRestartGameHasWinner(callback: (ctx: ReducerEventContext) => void) {
-    this.connection.onReducer("restart_game_has_winner", callback);
+  requestTakeback() {
+    this.connection.callReducer("request_takeback", new Uint8Array(0), this.setCallReducerFlags.requestTakebackFlags);
   }
 
-  removeOnRestartGameHasWinner(callback: (ctx: ReducerEventContext) => void) {
-    this.connection.offReducer("restart_game_has_winner", callback);
+  onRequestTakeback(callback: (ctx: ReducerEventContext) => void) {
+    this.connection.onReducer("request_takeback", callback);
   }
 
-  restartGameTableFull() {
-    this.connection.callReducer("restart_game_table_full", new Uint8Array(0), this.setCallReducerFlags.restartGameTableFullFlags);
+  removeOnRequestTakeback(callback: (ctx: ReducerEventContext) => void) {
+    this.connection.offReducer("request_takeback", callback);
   }
 
-  onRestartGameTableFull(callback: (ctx: ReducerEventContext) => void) {
-    this.connection.onReducer("restart_game_table_full", callback);
+  resign() {
+    this.connection.callReducer("resign", new Uint8Array(0), this.setCallReducerFlags.resignFlags);
   }
 
-  removeOnRestartGameTableFull(callback: (ctx: ReducerEventContext) => void) {
-    this.connection.offReducer("restart_game_table_full", callback);
+  onResign(callback: (ctx: ReducerEventContext) => void) {
+    this.connection.onReducer("resign", callback);
+  }
+
+  removeOnResign(callback: (ctx: ReducerEventContext) => void) {
+    this.connection.offReducer("resign", callback);
   }
 
   sendMessage(text: string) {
@@ -502,6 +1208,22 @@ export class RemoteReducers {
     this.connection.offReducer("send_message", callback);
   }
 
+  sendSpectatorMessage(text: string) {
+    const __args = { text };
+    let __writer = new BinaryWriter(1024);
+    SendSpectatorMessage.getTypeScriptAlgebraicType().serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("send_spectator_message", __argsBuffer, this.setCallReducerFlags.sendSpectatorMessageFlags);
+  }
+
+  onSendSpectatorMessage(callback: (ctx: ReducerEventContext, text: string) => void) {
+    this.connection.onReducer("send_spectator_message", callback);
+  }
+
+  removeOnSendSpectatorMessage(callback: (ctx: ReducerEventContext, text: string) => void) {
+    this.connection.offReducer("send_spectator_message", callback);
+  }
+
   setName(name: string) {
     const __args = { name };
     let __writer = new BinaryWriter(1024);
@@ -518,9 +1240,138 @@ export class RemoteReducers {
     this.connection.offReducer("set_name", callback);
   }
 
+  setSpectatorJoin(spectatorJoin: SpectatorJoin) {
+    const __args = { spectatorJoin };
+    let __writer = new BinaryWriter(1024);
+    SetSpectatorJoin.getTypeScriptAlgebraicType().serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("set_spectator_join", __argsBuffer, this.setCallReducerFlags.setSpectatorJoinFlags);
+  }
+
+  onSetSpectatorJoin(callback: (ctx: ReducerEventContext, spectatorJoin: SpectatorJoin) => void) {
+    this.connection.onReducer("set_spectator_join", callback);
+  }
+
+  removeOnSetSpectatorJoin(callback: (ctx: ReducerEventContext, spectatorJoin: SpectatorJoin) => void) {
+    this.connection.offReducer("set_spectator_join", callback);
+  }
+
+  setStartPolicy(startPolicy: StartPolicy) {
+    const __args = { startPolicy };
+    let __writer = new BinaryWriter(1024);
+    SetStartPolicy.getTypeScriptAlgebraicType().serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("set_start_policy", __argsBuffer, this.setCallReducerFlags.setStartPolicyFlags);
+  }
+
+  onSetStartPolicy(callback: (ctx: ReducerEventContext, startPolicy: StartPolicy) => void) {
+    this.connection.onReducer("set_start_policy", callback);
+  }
+
+  removeOnSetStartPolicy(callback: (ctx: ReducerEventContext, startPolicy: StartPolicy) => void) {
+    this.connection.offReducer("set_start_policy", callback);
+  }
+
+  spectateRoom(roomId: number) {
+    const __args = { roomId };
+    let __writer = new BinaryWriter(1024);
+    SpectateRoom.getTypeScriptAlgebraicType().serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("spectate_room", __argsBuffer, this.setCallReducerFlags.spectateRoomFlags);
+  }
+
+  onSpectateRoom(callback: (ctx: ReducerEventContext, roomId: number) => void) {
+    this.connection.onReducer("spectate_room", callback);
+  }
+
+  removeOnSpectateRoom(callback: (ctx: ReducerEventContext, roomId: number) => void) {
+    this.connection.offReducer("spectate_room", callback);
+  }
+
+  stepReplay(ply: number) {
+    const __args = { ply };
+    let __writer = new BinaryWriter(1024);
+    StepReplay.getTypeScriptAlgebraicType().serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("step_replay", __argsBuffer, this.setCallReducerFlags.stepReplayFlags);
+  }
+
+  onStepReplay(callback: (ctx: ReducerEventContext, ply: number) => void) {
+    this.connection.onReducer("step_replay", callback);
+  }
+
+  removeOnStepReplay(callback: (ctx: ReducerEventContext, ply: number) => void) {
+    this.connection.offReducer("step_replay", callback);
+  }
+
+  turnTimeout(timer: TurnTimer) {
+    const __args = { timer };
+    let __writer = new BinaryWriter(1024);
+    TurnTimeout.getTypeScriptAlgebraicType().serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("turn_timeout", __argsBuffer, this.setCallReducerFlags.turnTimeoutFlags);
+  }
+
+  onTurnTimeout(callback: (ctx: ReducerEventContext, timer: TurnTimer) => void) {
+    this.connection.onReducer("turn_timeout", callback);
+  }
+
+  removeOnTurnTimeout(callback: (ctx: ReducerEventContext, timer: TurnTimer) => void) {
+    this.connection.offReducer("turn_timeout", callback);
+  }
+
+  voteColumn(column: number) {
+    const __args = { column };
+    let __writer = new BinaryWriter(1024);
+    VoteColumn.getTypeScriptAlgebraicType().serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("vote_column", __argsBuffer, this.setCallReducerFlags.voteColumnFlags);
+  }
+
+  onVoteColumn(callback: (ctx: ReducerEventContext, column: number) => void) {
+    this.connection.onReducer("vote_column", callback);
+  }
+
+  removeOnVoteColumn(callback: (ctx: ReducerEventContext, column: number) => void) {
+    this.connection.offReducer("vote_column", callback);
+  }
+
+  voteRematch() {
+    this.connection.callReducer("vote_rematch", new Uint8Array(0), this.setCallReducerFlags.voteRematchFlags);
+  }
+
+  onVoteRematch(callback: (ctx: ReducerEventContext) => void) {
+    this.connection.onReducer("vote_rematch", callback);
+  }
+
+  removeOnVoteRematch(callback: (ctx: ReducerEventContext) => void) {
+    this.connection.offReducer("vote_rematch", callback);
+  }
+
+  voteTimeout(timer: VoteTimer) {
+    const __args = { timer };
+    let __writer = new BinaryWriter(1024);
+    VoteTimeout.getTypeScriptAlgebraicType().serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("vote_timeout", __argsBuffer, this.setCallReducerFlags.voteTimeoutFlags);
+  }
+
+  onVoteTimeout(callback: (ctx: ReducerEventContext, timer: VoteTimer) => void) {
+    this.connection.onReducer("vote_timeout", callback);
+  }
+
+  removeOnVoteTimeout(callback: (ctx: ReducerEventContext, timer: VoteTimer) => void) {
+    this.connection.offReducer("vote_timeout", callback);
+  }
+
 }
 
 export class SetReducerFlags {
+  acceptTakebackFlags: CallReducerFlags = 'FullUpdate';
+  acceptTakeback(flags: CallReducerFlags) {
+    this.acceptTakebackFlags = flags;
+  }
+
   autoDeleteGameHistoryFlags: CallReducerFlags = 'FullUpdate';
   autoDeleteGameHistory(flags: CallReducerFlags) {
     this.autoDeleteGameHistoryFlags = flags;
@@ -531,6 +1382,21 @@ export class SetReducerFlags {
     this.autoDeleteRoomIfAllOfflineFlags = flags;
   }
 
+  autoJoinTeamFlags: CallReducerFlags = 'FullUpdate';
+  autoJoinTeam(flags: CallReducerFlags) {
+    this.autoJoinTeamFlags = flags;
+  }
+
+  becomeSpectatorFlags: CallReducerFlags = 'FullUpdate';
+  becomeSpectator(flags: CallReducerFlags) {
+    this.becomeSpectatorFlags = flags;
+  }
+
+  closeReplayFlags: CallReducerFlags = 'FullUpdate';
+  closeReplay(flags: CallReducerFlags) {
+    this.closeReplayFlags = flags;
+  }
+
   createGameFlags: CallReducerFlags = 'FullUpdate';
   createGame(flags: CallReducerFlags) {
     this.createGameFlags = flags;
@@ -541,11 +1407,41 @@ export class SetReducerFlags {
     this.createRoomFlags = flags;
   }
 
+  decayRatingsFlags: CallReducerFlags = 'FullUpdate';
+  decayRatings(flags: CallReducerFlags) {
+    this.decayRatingsFlags = flags;
+  }
+
+  declineTakebackFlags: CallReducerFlags = 'FullUpdate';
+  declineTakeback(flags: CallReducerFlags) {
+    this.declineTakebackFlags = flags;
+  }
+
   dropPieceFlags: CallReducerFlags = 'FullUpdate';
   dropPiece(flags: CallReducerFlags) {
     this.dropPieceFlags = flags;
   }
 
+  endSeasonFlags: CallReducerFlags = 'FullUpdate';
+  endSeason(flags: CallReducerFlags) {
+    this.endSeasonFlags = flags;
+  }
+
+  equipSkinFlags: CallReducerFlags = 'FullUpdate';
+  equipSkin(flags: CallReducerFlags) {
+    this.equipSkinFlags = flags;
+  }
+
+  expireLeaderboardsFlags: CallReducerFlags = 'FullUpdate';
+  expireLeaderboards(flags: CallReducerFlags) {
+    this.expireLeaderboardsFlags = flags;
+  }
+
+  forfeitIfTeamOfflineFlags: CallReducerFlags = 'FullUpdate';
+  forfeitIfTeamOffline(flags: CallReducerFlags) {
+    this.forfeitIfTeamOfflineFlags = flags;
+  }
+
   helloFlags: CallReducerFlags = 'FullUpdate';
   hello(flags: CallReducerFlags) {
     this.helloFlags = flags;
@@ -571,14 +1467,34 @@ export class SetReducerFlags {
     this.leaveRoomFlags = flags;
   }
 
-  restartGameHasWinnerFlags: CallReducerFlags = 'FullUpdate';
-  restartGameHasWinner(flags: CallReducerFlags) {
-    this.restartGameHasWinnerFlags = flags;
+  openReplayFlags: CallReducerFlags = 'FullUpdate';
+  openReplay(flags: CallReducerFlags) {
+    this.openReplayFlags = flags;
+  }
+
+  popPieceFlags: CallReducerFlags = 'FullUpdate';
+  popPiece(flags: CallReducerFlags) {
+    this.popPieceFlags = flags;
+  }
+
+  purchaseSkinFlags: CallReducerFlags = 'FullUpdate';
+  purchaseSkin(flags: CallReducerFlags) {
+    this.purchaseSkinFlags = flags;
   }
 
-  restartGameTableFullFlags: CallReducerFlags = 'FullUpdate';
-  restartGameTableFull(flags: CallReducerFlags) {
-    this.restartGameTableFullFlags = flags;
+  rematchTimeoutFlags: CallReducerFlags = 'FullUpdate';
+  rematchTimeout(flags: CallReducerFlags) {
+    this.rematchTimeoutFlags = flags;
+  }
+
+  requestTakebackFlags: CallReducerFlags = 'FullUpdate';
+  requestTakeback(flags: CallReducerFlags) {
+    this.requestTakebackFlags = flags;
+  }
+
+  resignFlags: CallReducerFlags = 'FullUpdate';
+  resign(flags: CallReducerFlags) {
+    this.resignFlags = flags;
   }
 
   sendMessageFlags: CallReducerFlags = 'FullUpdate';
@@ -586,16 +1502,65 @@ export class SetReducerFlags {
     this.sendMessageFlags = flags;
   }
 
+  sendSpectatorMessageFlags: CallReducerFlags = 'FullUpdate';
+  sendSpectatorMessage(flags: CallReducerFlags) {
+    this.sendSpectatorMessageFlags = flags;
+  }
+
   setNameFlags: CallReducerFlags = 'FullUpdate';
   setName(flags: CallReducerFlags) {
     this.setNameFlags = flags;
   }
 
+  setSpectatorJoinFlags: CallReducerFlags = 'FullUpdate';
+  setSpectatorJoin(flags: CallReducerFlags) {
+    this.setSpectatorJoinFlags = flags;
+  }
+
+  setStartPolicyFlags: CallReducerFlags = 'FullUpdate';
+  setStartPolicy(flags: CallReducerFlags) {
+    this.setStartPolicyFlags = flags;
+  }
+
+  spectateRoomFlags: CallReducerFlags = 'FullUpdate';
+  spectateRoom(flags: CallReducerFlags) {
+    this.spectateRoomFlags = flags;
+  }
+
+  stepReplayFlags: CallReducerFlags = 'FullUpdate';
+  stepReplay(flags: CallReducerFlags) {
+    this.stepReplayFlags = flags;
+  }
+
+  turnTimeoutFlags: CallReducerFlags = 'FullUpdate';
+  turnTimeout(flags: CallReducerFlags) {
+    this.turnTimeoutFlags = flags;
+  }
+
+  voteColumnFlags: CallReducerFlags = 'FullUpdate';
+  voteColumn(flags: CallReducerFlags) {
+    this.voteColumnFlags = flags;
+  }
+
+  voteRematchFlags: CallReducerFlags = 'FullUpdate';
+  voteRematch(flags: CallReducerFlags) {
+    this.voteRematchFlags = flags;
+  }
+
+  voteTimeoutFlags: CallReducerFlags = 'FullUpdate';
+  voteTimeout(flags: CallReducerFlags) {
+    this.voteTimeoutFlags = flags;
+  }
+
 }
 
 export class RemoteTables {
   constructor(private connection: DbConnectionImpl) {}
 
+  get archivedGame(): ArchivedGameTableHandle {
+    return new ArchivedGameTableHandle(this.connection.clientCache.getOrCreateTable<ArchivedGame>(REMOTE_MODULE.tables.archived_game));
+  }
+
   get autoDeleteGameHistoryTimer(): AutoDeleteGameHistoryTimerTableHandle {
     return new AutoDeleteGameHistoryTimerTableHandle(this.connection.clientCache.getOrCreateTable<AutoDeleteGameHistoryTimer>(REMOTE_MODULE.tables.auto_delete_game_history_timer));
   }
@@ -604,6 +1569,14 @@ export class RemoteTables {
     return new AutoDeleteRoomTimerTableHandle(this.connection.clientCache.getOrCreateTable<AutoDeleteRoomTimer>(REMOTE_MODULE.tables.auto_delete_room_timer));
   }
 
+  get coinTransaction(): CoinTransactionTableHandle {
+    return new CoinTransactionTableHandle(this.connection.clientCache.getOrCreateTable<CoinTransaction>(REMOTE_MODULE.tables.coin_transaction));
+  }
+
+  get forfeitTimer(): ForfeitTimerTableHandle {
+    return new ForfeitTimerTableHandle(this.connection.clientCache.getOrCreateTable<ForfeitTimer>(REMOTE_MODULE.tables.forfeit_timer));
+  }
+
   get game(): GameTableHandle {
     return new GameTableHandle(this.connection.clientCache.getOrCreateTable<Game>(REMOTE_MODULE.tables.game));
   }
@@ -616,6 +1589,14 @@ export class RemoteTables {
     return new GameHistoryTableHandle(this.connection.clientCache.getOrCreateTable<GameHistory>(REMOTE_MODULE.tables.game_history));
   }
 
+  get gameMove(): GameMoveTableHandle {
+    return new GameMoveTableHandle(this.connection.clientCache.getOrCreateTable<GameMove>(REMOTE_MODULE.tables.game_move));
+  }
+
+  get gameReplay(): GameReplayTableHandle {
+    return new GameReplayTableHandle(this.connection.clientCache.getOrCreateTable<GameReplay>(REMOTE_MODULE.tables.game_replay));
+  }
+
   get joinRoom(): JoinRoomTableHandle {
     return new JoinRoomTableHandle(this.connection.clientCache.getOrCreateTable<JoinRoom>(REMOTE_MODULE.tables.join_room));
   }
@@ -624,25 +1605,85 @@ export class RemoteTables {
     return new JoinTeamTableHandle(this.connection.clientCache.getOrCreateTable<JoinTeam>(REMOTE_MODULE.tables.join_team));
   }
 
+  get leaderboardEntry(): LeaderboardEntryTableHandle {
+    return new LeaderboardEntryTableHandle(this.connection.clientCache.getOrCreateTable<LeaderboardEntry>(REMOTE_MODULE.tables.leaderboard_entry));
+  }
+
+  get leaderboardExpiry(): LeaderboardExpiryTableHandle {
+    return new LeaderboardExpiryTableHandle(this.connection.clientCache.getOrCreateTable<LeaderboardExpiry>(REMOTE_MODULE.tables.leaderboard_expiry));
+  }
+
+  get leaderboardExpiryTimer(): LeaderboardExpiryTimerTableHandle {
+    return new LeaderboardExpiryTimerTableHandle(this.connection.clientCache.getOrCreateTable<LeaderboardExpiryTimer>(REMOTE_MODULE.tables.leaderboard_expiry_timer));
+  }
+
   get message(): MessageTableHandle {
     return new MessageTableHandle(this.connection.clientCache.getOrCreateTable<Message>(REMOTE_MODULE.tables.message));
   }
 
+  get ownedSkin(): OwnedSkinTableHandle {
+    return new OwnedSkinTableHandle(this.connection.clientCache.getOrCreateTable<OwnedSkin>(REMOTE_MODULE.tables.owned_skin));
+  }
+
   get player(): PlayerTableHandle {
     return new PlayerTableHandle(this.connection.clientCache.getOrCreateTable<Player>(REMOTE_MODULE.tables.player));
   }
 
+  get playerRating(): PlayerRatingTableHandle {
+    return new PlayerRatingTableHandle(this.connection.clientCache.getOrCreateTable<PlayerRating>(REMOTE_MODULE.tables.player_rating));
+  }
+
+  get ratingDecayTimer(): RatingDecayTimerTableHandle {
+    return new RatingDecayTimerTableHandle(this.connection.clientCache.getOrCreateTable<RatingDecayTimer>(REMOTE_MODULE.tables.rating_decay_timer));
+  }
+
+  get rematchTimer(): RematchTimerTableHandle {
+    return new RematchTimerTableHandle(this.connection.clientCache.getOrCreateTable<RematchTimer>(REMOTE_MODULE.tables.rematch_timer));
+  }
+
+  get rematchVote(): RematchVoteTableHandle {
+    return new RematchVoteTableHandle(this.connection.clientCache.getOrCreateTable<RematchVote>(REMOTE_MODULE.tables.rematch_vote));
+  }
+
   get room(): RoomTableHandle {
     return new RoomTableHandle(this.connection.clientCache.getOrCreateTable<Room>(REMOTE_MODULE.tables.room));
   }
 
-  get statsOneMonth(): StatsOneMonthTableHandle {
-    return new StatsOneMonthTableHandle(this.connection.clientCache.getOrCreateTable<StatsOneMonth>(REMOTE_MODULE.tables.stats_one_month));
+  get season(): SeasonTableHandle {
+    return new SeasonTableHandle(this.connection.clientCache.getOrCreateTable<Season>(REMOTE_MODULE.tables.season));
+  }
+
+  get seasonTimer(): SeasonTimerTableHandle {
+    return new SeasonTimerTableHandle(this.connection.clientCache.getOrCreateTable<SeasonTimer>(REMOTE_MODULE.tables.season_timer));
+  }
+
+  get skin(): SkinTableHandle {
+    return new SkinTableHandle(this.connection.clientCache.getOrCreateTable<Skin>(REMOTE_MODULE.tables.skin));
+  }
+
+  get takebackRequest(): TakebackRequestTableHandle {
+    return new TakebackRequestTableHandle(this.connection.clientCache.getOrCreateTable<TakebackRequest>(REMOTE_MODULE.tables.takeback_request));
   }
 
   get team(): TeamTableHandle {
     return new TeamTableHandle(this.connection.clientCache.getOrCreateTable<Team>(REMOTE_MODULE.tables.team));
   }
+
+  get teamVote(): TeamVoteTableHandle {
+    return new TeamVoteTableHandle(this.connection.clientCache.getOrCreateTable<TeamVote>(REMOTE_MODULE.tables.team_vote));
+  }
+
+  get turnTimer(): TurnTimerTableHandle {
+    return new TurnTimerTableHandle(this.connection.clientCache.getOrCreateTable<TurnTimer>(REMOTE_MODULE.tables.turn_timer));
+  }
+
+  get voteTimer(): VoteTimerTableHandle {
+    return new VoteTimerTableHandle(this.connection.clientCache.getOrCreateTable<VoteTimer>(REMOTE_MODULE.tables.vote_timer));
+  }
+
+  get wallet(): WalletTableHandle {
+    return new WalletTableHandle(this.connection.clientCache.getOrCreateTable<Wallet>(REMOTE_MODULE.tables.wallet));
+  }
 }
 
 export class SubscriptionBuilder extends SubscriptionBuilderImpl<RemoteTables, RemoteReducers, SetReducerFlags> { }
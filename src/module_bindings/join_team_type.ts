@@ -9,31 +9,32 @@ import {
   AlgebraicValue,
   BinaryReader,
   BinaryWriter,
-  CallReducerFlags,
   ConnectionId,
   DbConnectionBuilder,
   DbConnectionImpl,
-  DbContext,
-  ErrorContextInterface,
-  Event,
-  EventContextInterface,
   Identity,
   ProductType,
   ProductTypeElement,
-  ReducerEventContextInterface,
   SubscriptionBuilderImpl,
-  SubscriptionEventContextInterface,
   SumType,
   SumTypeVariant,
   TableCache,
   TimeDuration,
   Timestamp,
   deepEqual,
+  type CallReducerFlags,
+  type DbContext,
+  type ErrorContextInterface,
+  type Event,
+  type EventContextInterface,
+  type ReducerEventContextInterface,
+  type SubscriptionEventContextInterface,
 } from "@clockworklabs/spacetimedb-sdk";
 export type JoinTeam = {
   roomId: number,
   joiner: Identity,
   teamId: number,
+  joinedAt: Timestamp,
 };
 
 /**
@@ -49,6 +50,7 @@ export namespace JoinTeam {
       new ProductTypeElement("roomId", AlgebraicType.createU32Type()),
       new ProductTypeElement("joiner", AlgebraicType.createIdentityType()),
       new ProductTypeElement("teamId", AlgebraicType.createU32Type()),
+      new ProductTypeElement("joinedAt", AlgebraicType.createTimestampType()),
     ]);
   }
 
@@ -9,32 +9,33 @@ import {
   AlgebraicValue,
   BinaryReader,
   BinaryWriter,
-  CallReducerFlags,
   ConnectionId,
   DbConnectionBuilder,
   DbConnectionImpl,
-  DbContext,
-  ErrorContextInterface,
-  Event,
-  EventContextInterface,
   Identity,
   ProductType,
   ProductTypeElement,
-  ReducerEventContextInterface,
   SubscriptionBuilderImpl,
-  SubscriptionEventContextInterface,
   SumType,
   SumTypeVariant,
   TableCache,
   TimeDuration,
   Timestamp,
   deepEqual,
+  type CallReducerFlags,
+  type DbContext,
+  type ErrorContextInterface,
+  type Event,
+  type EventContextInterface,
+  type ReducerEventContextInterface,
+  type SubscriptionEventContextInterface,
 } from "@clockworklabs/spacetimedb-sdk";
 export type Message = {
   roomId: number,
   sender: Identity,
   sentAt: Timestamp,
   text: string,
+  spectatorsOnly: boolean,
 };
 
 /**
@@ -51,6 +52,7 @@ export namespace Message {
       new ProductTypeElement("sender", AlgebraicType.createIdentityType()),
       new ProductTypeElement("sentAt", AlgebraicType.createTimestampType()),
       new ProductTypeElement("text", AlgebraicType.createStringType()),
+      new ProductTypeElement("spectatorsOnly", AlgebraicType.createBoolType()),
     ]);
   }
 
@@ -9,44 +9,44 @@ import {
   AlgebraicValue,
   BinaryReader,
   BinaryWriter,
-  CallReducerFlags,
   ConnectionId,
   DbConnectionBuilder,
   DbConnectionImpl,
-  DbContext,
-  ErrorContextInterface,
-  Event,
-  EventContextInterface,
   Identity,
   ProductType,
   ProductTypeElement,
-  ReducerEventContextInterface,
   SubscriptionBuilderImpl,
-  SubscriptionEventContextInterface,
   SumType,
   SumTypeVariant,
   TableCache,
   TimeDuration,
   Timestamp,
   deepEqual,
+  type CallReducerFlags,
+  type DbContext,
+  type ErrorContextInterface,
+  type Event,
+  type EventContextInterface,
+  type ReducerEventContextInterface,
+  type SubscriptionEventContextInterface,
 } from "@clockworklabs/spacetimedb-sdk";
-import { StatsOneMonth } from "./stats_one_month_type";
-import { EventContext, Reducer, RemoteReducers, RemoteTables } from ".";
+import { PlayerRating } from "./player_rating_type";
+import { type EventContext, type Reducer, RemoteReducers, RemoteTables } from ".";
 
 /**
- * Table handle for the table `stats_one_month`.
+ * Table handle for the table `player_rating`.
  *
- * Obtain a handle from the [`statsOneMonth`] property on [`RemoteTables`],
- * like `ctx.db.statsOneMonth`.
+ * Obtain a handle from the [`playerRating`] property on [`RemoteTables`],
+ * like `ctx.db.playerRating`.
  *
  * Users are encouraged not to explicitly reference this type,
  * but to directly chain method calls,
- * like `ctx.db.statsOneMonth.on_insert(...)`.
+ * like `ctx.db.playerRating.on_insert(...)`.
  */
-export class StatsOneMonthTableHandle {
-  tableCache: TableCache<StatsOneMonth>;
+export class PlayerRatingTableHandle {
+  tableCache: TableCache<PlayerRating>;
 
-  constructor(tableCache: TableCache<StatsOneMonth>) {
+  constructor(tableCache: TableCache<PlayerRating>) {
     this.tableCache = tableCache;
   }
 
@@ -54,24 +54,24 @@ export class StatsOneMonthTableHandle {
     return this.tableCache.count();
   }
 
-  iter(): Iterable<StatsOneMonth> {
+  iter(): Iterable<PlayerRating> {
     return this.tableCache.iter();
   }
   /**
-   * Access to the `player` unique index on the table `stats_one_month`,
+   * Access to the `player` unique index on the table `player_rating`,
    * which allows point queries on the field of the same name
-   * via the [`StatsOneMonthPlayerUnique.find`] method.
+   * via the [`PlayerRatingPlayerUnique.find`] method.
    *
    * Users are encouraged not to explicitly reference this type,
    * but to directly chain method calls,
-   * like `ctx.db.statsOneMonth.player().find(...)`.
+   * like `ctx.db.playerRating.player().find(...)`.
    *
-   * Get a handle on the `player` unique index on the table `stats_one_month`.
+   * Get a handle on the `player` unique index on the table `player_rating`.
    */
   player = {
     // Find the subscribed row whose `player` column value is equal to `col_val`,
     // if such a row is present in the client cache.
-    find: (col_val: Identity): StatsOneMonth | undefined => {
+    find: (col_val: Identity): PlayerRating | undefined => {
       for (let row of this.tableCache.iter()) {
         if (deepEqual(row.player, col_val)) {
           return row;
@@ -80,27 +80,27 @@ export class StatsOneMonthTableHandle {
     },
   };
 
-  onInsert = (cb: (ctx: EventContext, row: StatsOneMonth) => void) => {
+  onInsert = (cb: (ctx: EventContext, row: PlayerRating) => void) => {
     return this.tableCache.onInsert(cb);
   }
 
-  removeOnInsert = (cb: (ctx: EventContext, row: StatsOneMonth) => void) => {
+  removeOnInsert = (cb: (ctx: EventContext, row: PlayerRating) => void) => {
     return this.tableCache.removeOnInsert(cb);
   }
 
-  onDelete = (cb: (ctx: EventContext, row: StatsOneMonth) => void) => {
+  onDelete = (cb: (ctx: EventContext, row: PlayerRating) => void) => {
     return this.tableCache.onDelete(cb);
   }
 
-  removeOnDelete = (cb: (ctx: EventContext, row: StatsOneMonth) => void) => {
+  removeOnDelete = (cb: (ctx: EventContext, row: PlayerRating) => void) => {
     return this.tableCache.removeOnDelete(cb);
   }
 
   // Updates are only defined for tables with primary keys.
-  onUpdate = (cb: (ctx: EventContext, oldRow: StatsOneMonth, newRow: StatsOneMonth) => void) => {
+  onUpdate = (cb: (ctx: EventContext, oldRow: PlayerRating, newRow: PlayerRating) => void) => {
     return this.tableCache.onUpdate(cb);
   }
 
-  removeOnUpdate = (cb: (ctx: EventContext, onRow: StatsOneMonth, newRow: StatsOneMonth) => void) => {
+  removeOnUpdate = (cb: (ctx: EventContext, onRow: PlayerRating, newRow: PlayerRating) => void) => {
     return this.tableCache.removeOnUpdate(cb);
   }}
@@ -9,32 +9,38 @@ import {
   AlgebraicValue,
   BinaryReader,
   BinaryWriter,
-  CallReducerFlags,
   ConnectionId,
   DbConnectionBuilder,
   DbConnectionImpl,
-  DbContext,
-  ErrorContextInterface,
-  Event,
-  EventContextInterface,
   Identity,
   ProductType,
   ProductTypeElement,
-  ReducerEventContextInterface,
   SubscriptionBuilderImpl,
-  SubscriptionEventContextInterface,
   SumType,
   SumTypeVariant,
   TableCache,
   TimeDuration,
   Timestamp,
   deepEqual,
+  type CallReducerFlags,
+  type DbContext,
+  type ErrorContextInterface,
+  type Event,
+  type EventContextInterface,
+  type ReducerEventContextInterface,
+  type SubscriptionEventContextInterface,
 } from "@clockworklabs/spacetimedb-sdk";
+import { SpectatorJoin as __SpectatorJoin } from "./spectator_join_type";
+import { StartPolicy as __StartPolicy } from "./start_policy_type";
+
 export type Room = {
   id: number,
   title: string,
   owner: Identity,
   createdAt: Timestamp,
+  spectators: number,
+  spectatorJoin: __SpectatorJoin,
+  startPolicy: __StartPolicy,
 };
 
 /**
@@ -51,6 +57,9 @@ export namespace Room {
       new ProductTypeElement("title", AlgebraicType.createStringType()),
       new ProductTypeElement("owner", AlgebraicType.createIdentityType()),
       new ProductTypeElement("createdAt", AlgebraicType.createTimestampType()),
+      new ProductTypeElement("spectators", AlgebraicType.createU32Type()),
+      new ProductTypeElement("spectatorJoin", __SpectatorJoin.getTypeScriptAlgebraicType()),
+      new ProductTypeElement("startPolicy", __StartPolicy.getTypeScriptAlgebraicType()),
     ]);
   }
 
@@ -9,57 +9,55 @@ import {
   AlgebraicValue,
   BinaryReader,
   BinaryWriter,
-  CallReducerFlags,
   ConnectionId,
   DbConnectionBuilder,
   DbConnectionImpl,
-  DbContext,
-  ErrorContextInterface,
-  Event,
-  EventContextInterface,
   Identity,
   ProductType,
   ProductTypeElement,
-  ReducerEventContextInterface,
   SubscriptionBuilderImpl,
-  SubscriptionEventContextInterface,
   SumType,
   SumTypeVariant,
   TableCache,
   TimeDuration,
   Timestamp,
   deepEqual,
+  type CallReducerFlags,
+  type DbContext,
+  type ErrorContextInterface,
+  type Event,
+  type EventContextInterface,
+  type ReducerEventContextInterface,
+  type SubscriptionEventContextInterface,
 } from "@clockworklabs/spacetimedb-sdk";
-export type StatsOneMonth = {
-  player: Identity,
-  wins: number,
-  total: number,
+
+import { SpectatorJoin as __SpectatorJoin } from "./spectator_join_type";
+
+export type SetSpectatorJoin = {
+  spectatorJoin: __SpectatorJoin,
 };
 
 /**
  * A namespace for generated helper functions.
  */
-export namespace StatsOneMonth {
+export namespace SetSpectatorJoin {
   /**
   * A function which returns this type represented as an AlgebraicType.
   * This function is derived from the AlgebraicType used to generate this type.
   */
   export function getTypeScriptAlgebraicType(): AlgebraicType {
     return AlgebraicType.createProductType([
-      new ProductTypeElement("player", AlgebraicType.createIdentityType()),
-      new ProductTypeElement("wins", AlgebraicType.createU32Type()),
-      new ProductTypeElement("total", AlgebraicType.createU32Type()),
+      new ProductTypeElement("spectatorJoin", __SpectatorJoin.getTypeScriptAlgebraicType()),
     ]);
   }
 
-  export function serialize(writer: BinaryWriter, value: StatsOneMonth): void {
-    StatsOneMonth.getTypeScriptAlgebraicType().serialize(writer, value);
+  export function serialize(writer: BinaryWriter, value: SetSpectatorJoin): void {
+    SetSpectatorJoin.getTypeScriptAlgebraicType().serialize(writer, value);
   }
 
-  export function deserialize(reader: BinaryReader): StatsOneMonth {
-    return StatsOneMonth.getTypeScriptAlgebraicType().deserialize(reader);
+  export function deserialize(reader: BinaryReader): SetSpectatorJoin {
+    return SetSpectatorJoin.getTypeScriptAlgebraicType().deserialize(reader);
   }
 
 }
 
-
@@ -9,36 +9,36 @@ import {
   AlgebraicValue,
   BinaryReader,
   BinaryWriter,
-  CallReducerFlags,
   ConnectionId,
   DbConnectionBuilder,
   DbConnectionImpl,
-  DbContext,
-  ErrorContextInterface,
-  Event,
-  EventContextInterface,
   Identity,
   ProductType,
   ProductTypeElement,
-  ReducerEventContextInterface,
   SubscriptionBuilderImpl,
-  SubscriptionEventContextInterface,
   SumType,
   SumTypeVariant,
   TableCache,
   TimeDuration,
   Timestamp,
   deepEqual,
+  type CallReducerFlags,
+  type DbContext,
+  type ErrorContextInterface,
+  type Event,
+  type EventContextInterface,
+  type ReducerEventContextInterface,
+  type SubscriptionEventContextInterface,
 } from "@clockworklabs/spacetimedb-sdk";
-export type DroppedPiece = {
+export type TeamMask = {
   teamId: number,
-  dropper: Identity,
+  columns: bigint[],
 };
 
 /**
  * A namespace for generated helper functions.
  */
-export namespace DroppedPiece {
+export namespace TeamMask {
   /**
   * A function which returns this type represented as an AlgebraicType.
   * This function is derived from the AlgebraicType used to generate this type.
@@ -46,16 +46,16 @@ export namespace DroppedPiece {
   export function getTypeScriptAlgebraicType(): AlgebraicType {
     return AlgebraicType.createProductType([
       new ProductTypeElement("teamId", AlgebraicType.createU32Type()),
-      new ProductTypeElement("dropper", AlgebraicType.createIdentityType()),
+      new ProductTypeElement("columns", AlgebraicType.createArrayType(AlgebraicType.createU64Type())),
     ]);
   }
 
-  export function serialize(writer: BinaryWriter, value: DroppedPiece): void {
-    DroppedPiece.getTypeScriptAlgebraicType().serialize(writer, value);
+  export function serialize(writer: BinaryWriter, value: TeamMask): void {
+    TeamMask.getTypeScriptAlgebraicType().serialize(writer, value);
   }
 
-  export function deserialize(reader: BinaryReader): DroppedPiece {
-    return DroppedPiece.getTypeScriptAlgebraicType().deserialize(reader);
+  export function deserialize(reader: BinaryReader): TeamMask {
+    return TeamMask.getTypeScriptAlgebraicType().deserialize(reader);
   }
 
 }
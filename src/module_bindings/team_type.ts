@@ -9,31 +9,38 @@ import {
   AlgebraicValue,
   BinaryReader,
   BinaryWriter,
-  CallReducerFlags,
   ConnectionId,
   DbConnectionBuilder,
   DbConnectionImpl,
-  DbContext,
-  ErrorContextInterface,
-  Event,
-  EventContextInterface,
   Identity,
   ProductType,
   ProductTypeElement,
-  ReducerEventContextInterface,
   SubscriptionBuilderImpl,
-  SubscriptionEventContextInterface,
   SumType,
   SumTypeVariant,
   TableCache,
   TimeDuration,
   Timestamp,
   deepEqual,
+  type CallReducerFlags,
+  type DbContext,
+  type ErrorContextInterface,
+  type Event,
+  type EventContextInterface,
+  type ReducerEventContextInterface,
+  type SubscriptionEventContextInterface,
 } from "@clockworklabs/spacetimedb-sdk";
+import { BotDifficulty as __BotDifficulty } from "./bot_difficulty_type";
+
 export type Team = {
   id: number,
   gameId: number,
   name: string,
+  timeLeft: TimeDuration | undefined,
+  bot: __BotDifficulty | undefined,
+  eliminated: boolean,
+  lastDropper: Identity | undefined,
+  hadPlayer: boolean,
 };
 
 /**
@@ -49,6 +56,11 @@ export namespace Team {
       new ProductTypeElement("id", AlgebraicType.createU32Type()),
       new ProductTypeElement("gameId", AlgebraicType.createU32Type()),
       new ProductTypeElement("name", AlgebraicType.createStringType()),
+      new ProductTypeElement("timeLeft", AlgebraicType.createOptionType(AlgebraicType.createTimeDurationType())),
+      new ProductTypeElement("bot", AlgebraicType.createOptionType(__BotDifficulty.getTypeScriptAlgebraicType())),
+      new ProductTypeElement("eliminated", AlgebraicType.createBoolType()),
+      new ProductTypeElement("lastDropper", AlgebraicType.createOptionType(AlgebraicType.createIdentityType())),
+      new ProductTypeElement("hadPlayer", AlgebraicType.createBoolType()),
     ]);
   }
 
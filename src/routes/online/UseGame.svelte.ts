@@ -1,6 +1,22 @@
 import { SubscriptionHandle } from "$lib";
 import { Identity } from "@clockworklabs/spacetimedb-sdk";
-import { type DbConnection, type EventContext, type Game, type GameCurrentTeam, type JoinTeam, type ReducerEventContext, type Team } from "../../module_bindings";
+import { DropMode, TimeoutAction, Variant, type DbConnection, type EventContext, type Game, type GameCurrentTeam, type GameMove, type GameSettings, type JoinTeam, type ReducerEventContext, type Team } from "../../module_bindings";
+
+const DEFAULT_SETTINGS: GameSettings = {
+    rows: 6,
+    cols: 20,
+    streak: 4,
+    teams: 2,
+    maxTeamSize: undefined,
+    rematchQuorum: 100,
+    ranked: false,
+    variant: Variant.Classic,
+    turnSeconds: undefined,
+    totalSeconds: undefined,
+    timeoutAction: TimeoutAction.SkipTurn,
+    bot: undefined,
+    dropMode: DropMode.Anyone,
+};
 
 export class UseGame {
     // TODO: Try to refactor so that we can do some union type i.e. if loading is true, game is undefined, if not game is Game or null. Maybe we need to convert the class to a function that returns an object with the correct types.
@@ -29,16 +45,18 @@ export class UseGame {
     get joinTeams() {
         return this._joinTeams;
     }
-    bothTeamsHavePlayers = $derived(new Set(this._joinTeams.map((jt) => jt.teamId)).size === 2)
+    allTeamsHavePlayers = $derived(new Set(this._joinTeams.map((jt) => jt.teamId)).size === this._teams.length)
     // TODO: Change the mapping to from bigint
     playersToTeams = $derived(new Map<string, number>(this._joinTeams.map((jt) => [jt.joiner.toHexString(), jt.teamId])))
-    oppositeTeam = $derived.by(() => {
-        if (!this.yourJoinTeam || !this._teams.length) {
-            return null;
+    /**
+     * Teams you can move to, there are 2 to 4 teams in a game.
+     */
+    otherTeams = $derived.by(() => {
+        if (!this.yourJoinTeam) {
+            return [];
         }
         const yourTeamId = this.yourJoinTeam.teamId;
-        const oppositeTeam = this._teams.find((team) => team.id !== yourTeamId);
-        return oppositeTeam ?? null;
+        return this._teams.filter((team) => team.id !== yourTeamId && !team.bot && !team.eliminated);
     })
 
     /**
@@ -75,6 +93,45 @@ export class UseGame {
     }
 
 
+    private readonly gameMoveHandle: SubscriptionHandle;
+    private readonly gameMoveOnInsert: (ctx: EventContext, move: GameMove) => void
+    private readonly gameMoveOnDelete: (ctx: EventContext, move: GameMove) => void
+    private _gameMoves = $state<GameMove[]>([]);
+    /**
+     * Pieces of the board from the top row, with the player who dropped each of them.
+     */
+    table = $derived.by(() => {
+        if (!this._game) {
+            return null;
+        }
+        const { settings, board, round } = this._game;
+        // replay the moves of the round to know who dropped the pieces left in each column
+        const droppers: Identity[][] = Array.from({ length: settings.cols }, () => []);
+        const moves = this._gameMoves.filter((m) => m.round === round).sort((a, b) => a.ply - b.ply);
+        for (const move of moves) {
+            if (move.kind.tag === 'Drop') {
+                droppers[move.column]?.push(move.dropper);
+            } else {
+                droppers[move.column]?.shift();
+            }
+        }
+        return Array.from({ length: settings.rows }, (_, row) =>
+            Array.from({ length: settings.cols }, (_, col) => {
+                // bit `i` of a column is the `i`-th piece from the bottom
+                const i = settings.rows - 1 - row;
+                if (i >= (board.heights[col] ?? 0)) {
+                    return undefined;
+                }
+                const mask = board.masks.find((m) => (m.columns[col] >> BigInt(i)) & 1n);
+                if (!mask) {
+                    return undefined;
+                }
+                return { teamId: mask.teamId, dropper: droppers[col]?.[i] as Identity | undefined };
+            })
+        );
+    })
+
+
     constructor(private readonly conn: DbConnection, roomId: number, private readonly yourIdentity: Identity) {
         $effect(() => {
             if (this.activeSubscriptions === this.subscriptions) {
@@ -199,11 +256,32 @@ export class UseGame {
             })
             .subscribe(`SELECT * FROM game_current_team WHERE game_id = '${roomId}'`);
 
+        this.gameMoveOnInsert = (ctx, move) => {
+            this._gameMoves.push(move);
+        }
+        this.gameMoveOnDelete = (ctx, move) => {
+            const deleted = this._gameMoves.findIndex((m) => m.id === move.id);
+            if (deleted !== -1) {
+                this._gameMoves.splice(deleted, 1);
+            }
+        }
+        conn.db.gameMove.onInsert(this.gameMoveOnInsert);
+        conn.db.gameMove.onDelete(this.gameMoveOnDelete);
+
+        this.subscriptions++;
+        this.gameMoveHandle = conn
+            .subscriptionBuilder()
+            .onApplied(() => {
+                this.activeSubscriptions++;
+                this._gameMoves = Array.from(conn.db.gameMove.iter()).filter((m) => m.gameId === roomId);
+            })
+            .onError((ctx) => {
+                console.error('Error fetching game moves:', ctx.event);
+            })
+            .subscribe(`SELECT * FROM game_move WHERE game_id = '${roomId}'`);
+
         this.teamOnInsert = (ctx, team) => {
             this._teams.push(team);
-            if (this._teams.length > 2) {
-                throw new Error('There cannot be more than two teams. Check the subscription or something is wrong on the backend.')
-            }
             this._teams.sort((a, b) => a.id - b.id);
         }
 
@@ -227,9 +305,9 @@ export class UseGame {
             .subscribe(`SELECT * FROM team WHERE game_id = '${roomId}'`);
     }
 
-    createGame() {
+    createGame(settings: GameSettings = DEFAULT_SETTINGS) {
         this._gameJoining = true;
-        this.conn.reducers.createGame()
+        this.conn.reducers.createGame(settings)
         return new Promise<void>(resolve => {
             const onCreateGame = (ctx: ReducerEventContext) => {
                 this._gameJoining = false;
@@ -314,6 +392,25 @@ export class UseGame {
         })
     }
 
+    private stopGameMove() {
+        const removeListeners = () => {
+            this.conn.db.gameMove.removeOnInsert(this.gameMoveOnInsert);
+            this.conn.db.gameMove.removeOnDelete(this.gameMoveOnDelete);
+        }
+
+        return new Promise<void>(resolve => {
+            if (this.gameMoveHandle.isActive()) {
+                this.gameMoveHandle.unsubscribeThen(() => {
+                    removeListeners()
+                    resolve();
+                });
+            } else {
+                removeListeners()
+                resolve();
+            }
+        })
+    }
+
     private stopTeam() {
         const removeListeners = () => {
             this.conn.db.team.removeOnInsert(this.teamOnInsert);
@@ -337,6 +434,7 @@ export class UseGame {
             this.stopGame(),
             this.stopJoinTeam(),
             this.stopGameCurrentTeam(),
+            this.stopGameMove(),
             this.stopTeam(),
         ])
     }
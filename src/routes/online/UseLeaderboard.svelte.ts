@@ -1,55 +1,65 @@
-import type { StatsOneMonth } from './../../module_bindings/stats_one_month_type'
-import type { DbConnection, EventContext } from "../../module_bindings";
+import type { DbConnection, EventContext, LeaderboardEntry } from "../../module_bindings";
 import type { SubscriptionHandle } from '$lib';
 
+/**
+ * Lowest rank shown, the players sharing it are all shown.
+ */
+const LEADERBOARD_SIZE = 100
+
 export class UseLeaderboard {
     public oneMonth = $state<{
         loading: true
     } | {
-        loading: false, data: (StatsOneMonth & { winRate: number })[]
+        loading: false, data: LeaderboardEntry[]
     }>({ loading: true });
 
-    private readonly sort = (data: object & { winRate: number }[]) => {
+    private readonly sort = (data: LeaderboardEntry[]) => {
         return data.sort((a, b) => {
-            return b.winRate - a.winRate
+            return a.rank - b.rank || b.winRate - a.winRate
         })
     }
 
     private readonly oneMonthSubHandle: SubscriptionHandle
-    private readonly oneMonthOnInsert: (ctx: EventContext, row: StatsOneMonth) => void = (ctx, row) => {
+    private readonly oneMonthOnInsert: (ctx: EventContext, row: LeaderboardEntry) => void = (ctx, row) => {
         if (this.oneMonth.loading) {
             console.error('One month stats not set yet')
             return
         }
+        if (row.board.tag !== 'OneMonth') {
+            return
+        }
 
-        this.oneMonth.data.push({
-            ...row,
-            winRate: row.wins / row.total
-        })
+        this.oneMonth.data.push(row)
 
         this.sort(this.oneMonth.data)
     }
-    private readonly oneMonthOnUpdate: (ctx: EventContext, oldRow: StatsOneMonth, newRow: StatsOneMonth) => void = (ctx, oldRow, newRow) => {
+    private readonly oneMonthOnUpdate: (ctx: EventContext, oldRow: LeaderboardEntry, newRow: LeaderboardEntry) => void = (ctx, oldRow, newRow) => {
         if (this.oneMonth.loading) {
             console.error('One month stats not set yet')
             return
         }
+        if (newRow.board.tag !== 'OneMonth') {
+            return
+        }
 
-        const idx = this.oneMonth.data.findIndex((r) => r.player.data === oldRow.player.data)
+        const idx = this.oneMonth.data.findIndex((r) => r.id === oldRow.id)
         if (idx !== -1) {
-            this.oneMonth.data[idx] = { ...newRow, winRate: newRow.wins / newRow.total }
+            this.oneMonth.data[idx] = newRow
         } else {
             console.error('Update for non-existing row??', oldRow, newRow)
         }
 
         this.sort(this.oneMonth.data)
     }
-    private readonly oneMonthOnDelete: (ctx: EventContext, row: StatsOneMonth) => void = (ctx, row) => {
+    private readonly oneMonthOnDelete: (ctx: EventContext, row: LeaderboardEntry) => void = (ctx, row) => {
         if (this.oneMonth.loading) {
             console.error('One month stats not set yet')
             return
         }
-        const idx = this.oneMonth.data.findIndex((r) => r.player.data === row.player.data)
+        if (row.board.tag !== 'OneMonth') {
+            return
+        }
+        const idx = this.oneMonth.data.findIndex((r) => r.id === row.id)
         if (idx === -1) {
             console.error('Delete for non-existing row??', row)
             return
@@ -64,20 +74,21 @@ export class UseLeaderboard {
     private readonly setupOneMonth = () => {
         return this.conn.subscriptionBuilder().onApplied(() => {
             this.oneMonth = {
-                loading: false, data: []
+                loading: false,
+                data: this.sort(Array.from(this.conn.db.leaderboardEntry.iter()).filter((r) => r.board.tag === 'OneMonth'))
             }
-            this.conn.db.statsOneMonth.onInsert(this.oneMonthOnInsert)
-            this.conn.db.statsOneMonth.onUpdate(this.oneMonthOnUpdate)
-            this.conn.db.statsOneMonth.onDelete(this.oneMonthOnDelete)
+            this.conn.db.leaderboardEntry.onInsert(this.oneMonthOnInsert)
+            this.conn.db.leaderboardEntry.onUpdate(this.oneMonthOnUpdate)
+            this.conn.db.leaderboardEntry.onDelete(this.oneMonthOnDelete)
         }).onError(ctx => {
             console.error('Error subscribing to one month stats:', ctx.event);
-        }).subscribe(`SELECT * FROM stats_one_month`)
+        }).subscribe(`SELECT * FROM leaderboard_entry WHERE rank <= ${LEADERBOARD_SIZE}`)
     }
 
     private readonly removeOneMonthListeners = () => {
-        this.conn.db.statsOneMonth.removeOnInsert(this.oneMonthOnInsert)
-        this.conn.db.statsOneMonth.removeOnUpdate(this.oneMonthOnUpdate)
-        this.conn.db.statsOneMonth.removeOnDelete(this.oneMonthOnDelete)
+        this.conn.db.leaderboardEntry.removeOnInsert(this.oneMonthOnInsert)
+        this.conn.db.leaderboardEntry.removeOnUpdate(this.oneMonthOnUpdate)
+        this.conn.db.leaderboardEntry.removeOnDelete(this.oneMonthOnDelete)
     }
 
     private stopOneMonth = () => {